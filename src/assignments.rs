@@ -0,0 +1,187 @@
+use std::hash::Hash;
+
+use crate::{binary_decision_diagram::*, FormulaRoot};
+
+/// The satisfying cubes of a formula, one per path to the true leaf, as the values of the
+/// variables tested along the path in the variable order. The other variables are
+/// don't-cares, and the cubes are disjoint.
+///
+/// The cubes are found as they are asked for. The nodes still to visit stay referenced,
+/// so formulas may be built and variables reordered in between.
+pub struct Cubes<T> {
+    root: FormulaRoot<T>,
+    // The nodes still to visit, with the path leading to each
+    stack: Vec<(NodeHandler, Vec<(usize, bool)>)>,
+}
+
+impl<T> Cubes<T> {
+    pub(crate) fn new(root: &FormulaRoot<T>) -> Cubes<T> {
+        let mut cubes = Cubes {
+            root: root.clone(),
+            stack: vec![],
+        };
+        cubes.push(root.node_handler(), vec![]);
+        cubes
+    }
+
+    fn push(&mut self, node: NodeHandler, path: Vec<(usize, bool)>) {
+        let context = &mut self.root.manager().0.borrow_mut();
+        context.diagram.reference(&node);
+        self.stack.push((node, path));
+    }
+}
+
+impl<T> Iterator for Cubes<T>
+where
+    T: Clone,
+{
+    type Item = Vec<(T, bool)>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let (false_leaf, true_leaf) = BinaryDecisionDiagram::<usize>::get_leaves();
+        while let Some((node, path)) = self.stack.pop() {
+            let manager = self.root.manager().clone();
+            manager.0.borrow_mut().diagram.dereference(&node);
+            if node == true_leaf {
+                let context = manager.0.borrow();
+                let cube = path
+                    .into_iter()
+                    .map(|(variable, value)| (context.inverse_table[variable].clone(), value))
+                    .collect();
+                return Some(cube);
+            }
+            if node == false_leaf {
+                continue;
+            }
+            let (variable, low, high) = {
+                let diagram = &manager.0.borrow().diagram;
+                let variable = match node.get_element(diagram) {
+                    Element::Variable(variable) => *variable,
+                    Element::Binary(_) => unreachable!(),
+                };
+                (
+                    variable,
+                    node.get_child(diagram, BinaryIndex::Left).unwrap(),
+                    node.get_child(diagram, BinaryIndex::Right).unwrap(),
+                )
+            };
+            // The low child comes first
+            self.push(high, [path.as_slice(), &[(variable, true)]].concat());
+            self.push(low, [path.as_slice(), &[(variable, false)]].concat());
+        }
+        None
+    }
+}
+
+impl<T> Drop for Cubes<T> {
+    fn drop(&mut self) {
+        let diagram = &mut self.root.manager().0.borrow_mut().diagram;
+        for (node, _) in &self.stack {
+            diagram.dereference(node);
+        }
+    }
+}
+
+/// The satisfying assignments of a formula to a set of variables, as the values of the
+/// variables in the order of the set. They are the cubes of the formula with their
+/// don't-cares expanded, and come just as lazily.
+pub struct Minterms<T> {
+    cubes: Cubes<T>,
+    variables: Vec<T>,
+    // The next assignment of the current cube, with the positions of its don't-cares
+    current: Option<(Vec<bool>, Vec<usize>)>,
+}
+
+impl<T> Minterms<T>
+where
+    T: Eq + Hash + Clone,
+{
+    pub(crate) fn new(root: &FormulaRoot<T>, variables: impl IntoIterator<Item = T>) -> Self {
+        let mut names: Vec<T> = vec![];
+        for name in variables {
+            if !names.contains(&name) {
+                names.push(name);
+            }
+        }
+        let context = root.manager().0.borrow();
+        assert!(
+            context
+                .diagram
+                .support(root.node_handler())
+                .iter()
+                .all(|variable| names.contains(&context.inverse_table[*variable])),
+            "The formula depends on a variable that is not expanded"
+        );
+        drop(context);
+        Minterms {
+            cubes: Cubes::new(root),
+            variables: names,
+            current: None,
+        }
+    }
+}
+
+impl<T> Iterator for Minterms<T>
+where
+    T: Eq + Clone,
+{
+    type Item = Vec<(T, bool)>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.current.is_none() {
+            let cube = self.cubes.next()?;
+            let mut values = vec![false; self.variables.len()];
+            let mut free = vec![];
+            for (position, variable) in self.variables.iter().enumerate() {
+                match cube.iter().find(|(name, _)| name == variable) {
+                    Some((_, value)) => values[position] = *value,
+                    None => free.push(position),
+                }
+            }
+            self.current = Some((values, free));
+        }
+
+        let (values, free) = self.current.as_mut().unwrap();
+        let minterm = self.variables.iter().cloned().zip(values.clone()).collect();
+        // Counts up in binary over the don't-cares, the last one being the lowest bit
+        match free.iter().rposition(|position| !values[*position]) {
+            Some(index) => {
+                values[free[index]] = true;
+                for position in &free[index + 1..] {
+                    values[*position] = false;
+                }
+            }
+            None => self.current = None,
+        }
+        Some(minterm)
+    }
+}
+
+#[test]
+fn assignments_test() {
+    let manager = crate::Manager::new();
+    manager.declare_order(["a", "b", "c"].map(String::from));
+    let f = manager.add_formula("a -> b & c").unwrap();
+    let show = |assignment: Vec<(String, bool)>| {
+        assignment
+            .into_iter()
+            .map(|(name, value)| format!("{name}={}", value as u8))
+            .collect::<Vec<_>>()
+            .join(",")
+    };
+    let cubes: Vec<String> = f.cubes().map(show).collect();
+    assert_eq!(cubes, ["a=0", "a=1,b=1,c=1"]);
+    let mut minterms = f.minterms(["c", "a", "b"].map(String::from));
+    assert_eq!(minterms.next().map(show).as_deref(), Some("c=0,a=0,b=0"));
+    // The nodes still to visit survive building and collecting garbage in between
+    drop(f);
+    manager.add_formula("b | c & d").unwrap();
+    manager.collect_garbage();
+    let rest: Vec<String> = minterms.map(show).collect();
+    assert_eq!(
+        rest,
+        ["c=0,a=0,b=1", "c=1,a=0,b=0", "c=1,a=0,b=1", "c=1,a=1,b=1"]
+    );
+    assert_eq!(manager.constant(false).cubes().count(), 0);
+    assert_eq!(manager.constant(true).minterms(vec![]).count(), 1);
+}
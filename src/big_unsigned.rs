@@ -0,0 +1,169 @@
+use std::{
+    cmp::Ordering,
+    fmt::Display,
+    ops::{Add, Shl, Sub},
+};
+
+/// An unsigned integer of any size, enough to count the models of formulas over more than
+/// 64 variables.
+#[derive(Clone, Debug, Default, PartialEq, Eq, Hash)]
+pub struct BigUnsigned {
+    // Little endian, without leading zeros, so zero has no digits
+    digits: Vec<u32>,
+}
+
+impl BigUnsigned {
+    /// The value if it fits in a `u64`.
+    pub fn to_u64(&self) -> Option<u64> {
+        match self.digits.len() {
+            0..=2 => Some(
+                self.digits
+                    .iter()
+                    .rev()
+                    .fold(0, |value, digit| value << 32 | *digit as u64),
+            ),
+            _ => None,
+        }
+    }
+
+    /// Whether the value is 0.
+    pub fn is_zero(&self) -> bool {
+        self.digits.is_empty()
+    }
+
+    fn normalize(mut self) -> Self {
+        while self.digits.last() == Some(&0) {
+            self.digits.pop();
+        }
+        self
+    }
+}
+
+impl From<u64> for BigUnsigned {
+    fn from(value: u64) -> Self {
+        BigUnsigned {
+            digits: vec![value as u32, (value >> 32) as u32],
+        }
+        .normalize()
+    }
+}
+
+impl Add for BigUnsigned {
+    type Output = BigUnsigned;
+
+    fn add(self, rhs: Self) -> Self::Output {
+        let length = usize::max(self.digits.len(), rhs.digits.len());
+        let mut digits = Vec::with_capacity(length + 1);
+        let mut carry = 0;
+        for i in 0..length {
+            let sum = *self.digits.get(i).unwrap_or(&0) as u64
+                + *rhs.digits.get(i).unwrap_or(&0) as u64
+                + carry;
+            digits.push(sum as u32);
+            carry = sum >> 32;
+        }
+        digits.push(carry as u32);
+        BigUnsigned { digits }.normalize()
+    }
+}
+
+/// Panics if `rhs` is greater than `self`.
+impl Sub for BigUnsigned {
+    type Output = BigUnsigned;
+
+    fn sub(self, rhs: Self) -> Self::Output {
+        assert!(self >= rhs, "Subtraction overflow");
+        let mut digits = Vec::with_capacity(self.digits.len());
+        let mut borrow = 0;
+        for (i, digit) in self.digits.iter().enumerate() {
+            let subtrahend = *rhs.digits.get(i).unwrap_or(&0) as i64 + borrow;
+            let difference = *digit as i64 - subtrahend;
+            borrow = (difference < 0) as i64;
+            digits.push((difference + (borrow << 32)) as u32);
+        }
+        BigUnsigned { digits }.normalize()
+    }
+}
+
+/// Multiplication by a power of 2.
+impl Shl<usize> for BigUnsigned {
+    type Output = BigUnsigned;
+
+    fn shl(self, rhs: usize) -> Self::Output {
+        if self.is_zero() {
+            return self;
+        }
+        let (whole, bits) = (rhs / 32, rhs % 32);
+        let mut digits = vec![0; whole];
+        let mut carry = 0;
+        for digit in self.digits {
+            let shifted = (digit as u64) << bits | carry;
+            digits.push(shifted as u32);
+            carry = shifted >> 32;
+        }
+        digits.push(carry as u32);
+        BigUnsigned { digits }.normalize()
+    }
+}
+
+impl Ord for BigUnsigned {
+    fn cmp(&self, other: &Self) -> Ordering {
+        self.digits
+            .len()
+            .cmp(&other.digits.len())
+            .then_with(|| self.digits.iter().rev().cmp(other.digits.iter().rev()))
+    }
+}
+
+impl PartialOrd for BigUnsigned {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Display for BigUnsigned {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        // Chunks of 9 decimal digits, from the least significant one
+        const BASE: u64 = 1_000_000_000;
+        let mut digits = self.digits.clone();
+        let mut chunks = vec![];
+        while !digits.is_empty() {
+            let mut remainder = 0;
+            for digit in digits.iter_mut().rev() {
+                let value = remainder << 32 | *digit as u64;
+                *digit = (value / BASE) as u32;
+                remainder = value % BASE;
+            }
+            chunks.push(remainder);
+            while digits.last() == Some(&0) {
+                digits.pop();
+            }
+        }
+        match chunks.pop() {
+            None => write!(f, "0"),
+            Some(first) => {
+                write!(f, "{first}")?;
+                chunks
+                    .iter()
+                    .rev()
+                    .try_for_each(|chunk| write!(f, "{chunk:09}"))
+            }
+        }
+    }
+}
+
+#[test]
+fn big_unsigned_test() {
+    let one = BigUnsigned::from(1);
+    assert_eq!(BigUnsigned::default().to_string(), "0");
+    assert_eq!((one.clone() << 64).to_string(), "18446744073709551616");
+    assert_eq!(
+        (one.clone() << 100).to_string(),
+        "1267650600228229401496703205376"
+    );
+    let maximum = (one.clone() << 64) - one.clone();
+    assert_eq!(maximum.to_u64(), Some(u64::MAX));
+    assert_eq!((maximum.clone() + one.clone()).to_u64(), None);
+    assert_eq!(maximum.clone() + one.clone() - maximum, one);
+    assert!(BigUnsigned::from(1 << 40) > BigUnsigned::from(u32::MAX as u64));
+}
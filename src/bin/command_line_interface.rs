@@ -1,14 +1,212 @@
+use std::collections::HashMap;
+
 use reduced_ordered_binary_decision_diagram::*;
 
+const USAGE: &str =
+    "Usage: command_line_interface [count | --all-sat [--limit N]] [--order a,b,c | --heuristic appearance|depth-first|weighted-fan-in|force|best] [--reorder] [--assume x=1,y=0]";
+
+// What to print about the formula
+enum Mode {
+    // The diagram in DOT
+    Diagram,
+    // The number of satisfying assignments
+    Count,
+    // The satisfying assignments, up to a limit
+    AllSatisfying,
+}
+
+struct Options {
+    mode: Mode,
+    // The most satisfying assignments to print
+    limit: Option<usize>,
+    // Variables to place first, in this order
+    order: Vec<String>,
+    heuristic: Option<OrderingHeuristic>,
+    // Whether to sift after building the formula
+    reorder: bool,
+    // Values the variables are fixed to
+    assumptions: HashMap<String, bool>,
+}
+
+fn parse_arguments() -> Result<Options, String> {
+    let mut options = Options {
+        mode: Mode::Diagram,
+        limit: None,
+        order: vec![],
+        heuristic: None,
+        reorder: false,
+        assumptions: HashMap::new(),
+    };
+    let mut arguments = std::env::args().skip(1);
+    while let Some(argument) = arguments.next() {
+        match argument.as_str() {
+            "--order" => {
+                let order = arguments
+                    .next()
+                    .ok_or("Missing the variables after --order")?;
+                options.order = order
+                    .split(',')
+                    .map(|name| name.trim().to_string())
+                    .collect();
+            }
+            "--heuristic" => {
+                let heuristic = arguments
+                    .next()
+                    .ok_or("Missing the heuristic after --heuristic")?;
+                options.heuristic = Some(heuristic.parse()?);
+            }
+            "count" => options.mode = Mode::Count,
+            "--all-sat" => options.mode = Mode::AllSatisfying,
+            "--limit" => {
+                let limit = arguments.next().ok_or("Missing the number after --limit")?;
+                let limit = limit
+                    .parse()
+                    .map_err(|_| format!("Expected a number after --limit instead of {limit}"))?;
+                options.limit = Some(limit);
+            }
+            "--reorder" => options.reorder = true,
+            "--assume" => {
+                let assumptions = arguments
+                    .next()
+                    .ok_or("Missing the assignment after --assume")?;
+                for assumption in assumptions.split(',') {
+                    let (name, value) = assumption
+                        .split_once('=')
+                        .ok_or(format!("Expected x=0 or x=1 instead of {assumption}"))?;
+                    let value = match value.trim() {
+                        "0" => false,
+                        "1" => true,
+                        _ => return Err(format!("Expected 0 or 1 as the value of {name}")),
+                    };
+                    options.assumptions.insert(name.trim().to_string(), value);
+                }
+            }
+            _ => return Err(format!("Unknown argument {argument}")),
+        }
+    }
+    if !options.order.is_empty() && options.heuristic.is_some() {
+        return Err("--order and --heuristic cannot be used together".to_string());
+    }
+    if options.limit.is_some() && !matches!(options.mode, Mode::AllSatisfying) {
+        return Err("--limit can only be used with --all-sat".to_string());
+    }
+    Ok(options)
+}
+
+// Reads the formula, preceded by the lines declaring the order like `order: a < b < c`, if any
+fn read_input() -> String {
+    let mut input = String::new();
+    loop {
+        let mut line = String::new();
+        let length = std::io::stdin()
+            .read_line(&mut line)
+            .expect("Error reading from the standard input");
+        let is_order_declaration = line
+            .trim_start()
+            .strip_prefix("order")
+            .is_some_and(|rest| rest.trim_start().starts_with(':'));
+        input.push_str(&line);
+        if length == 0 || !is_order_declaration {
+            return input;
+        }
+    }
+}
+
+// The variables of the input, but the assumed ones and those only bound by a quantifier
+fn free_variables(
+    root: &FormulaRoot<String>,
+    assumptions: &HashMap<String, bool>,
+    bound: &[String],
+) -> Vec<String> {
+    root.manager()
+        .variable_order()
+        .into_iter()
+        .filter(|name| !assumptions.contains_key(name) && !bound.contains(name))
+        .collect()
+}
+
 fn main() {
-    let mut formula = String::new();
-    std::io::stdin()
-        .read_line(&mut formula)
-        .expect("Error reading from the standard input");
-    match construct_robdd(&formula) {
-        Ok((_, root)) => {
-            println!("{}", root);
-            println!("");
+    let options = parse_arguments().unwrap_or_else(|message| {
+        eprintln!("{message}");
+        eprintln!("{USAGE}");
+        std::process::exit(2)
+    });
+    let formula = read_input();
+    let order: Vec<&str> = options.order.iter().map(String::as_str).collect();
+    let result = match options.heuristic {
+        Some(heuristic) => construct_robdd_with_heuristic(&formula, heuristic)
+            .map(|(root, report)| (root, Some(report))),
+        None => construct_robdd_with_order(&formula, &order).map(|root| (root, None)),
+    };
+    match result {
+        Ok((root, report)) => {
+            let root = match options.assumptions.is_empty() {
+                true => root,
+                false => root.manager().restrict(&root, &options.assumptions),
+            };
+            if options.reorder {
+                root.manager().reorder();
+            }
+            // The input parsed already
+            let bound = bound_variables(&formula).unwrap();
+            match options.mode {
+                Mode::Diagram => {
+                    println!("{}", root);
+                    println!();
+                    // A lint for redundant constraints
+                    let removable = root.removable_variables(free_variables(
+                        &root,
+                        &options.assumptions,
+                        &bound,
+                    ));
+                    if !removable.is_empty() {
+                        println!(
+                            "Variables of the input the formula does not depend on: {}",
+                            removable.join(", ")
+                        );
+                        println!();
+                    }
+                }
+                Mode::Count => {
+                    let variables = free_variables(&root, &options.assumptions, &bound);
+                    let count = variables.len();
+                    println!(
+                        "{} satisfying assignments over {} variables",
+                        root.sat_count(variables),
+                        count
+                    );
+                    return;
+                }
+                Mode::AllSatisfying => {
+                    let variables = free_variables(&root, &options.assumptions, &bound);
+                    let minterms = root
+                        .minterms(variables)
+                        .take(options.limit.unwrap_or(usize::MAX));
+                    let mut satisfiable = false;
+                    for minterm in minterms {
+                        // In the syntax of --assume
+                        let minterm: Vec<String> = minterm
+                            .into_iter()
+                            .map(|(name, value)| format!("{name}={}", value as u8))
+                            .collect();
+                        println!("{}", minterm.join(","));
+                        satisfiable = true;
+                    }
+                    if !satisfiable {
+                        println!("Unsatisfiable");
+                    }
+                    return;
+                }
+            }
+            if let Some(report) = report {
+                println!(
+                    "Heuristic {}: order {}, {} nodes",
+                    report.heuristic,
+                    report.order.join(" < "),
+                    report.node_count
+                );
+                println!();
+            }
             println!("To visualize the diagram, paste the output to http://viz-js.com/");
         }
         Err(e) => {
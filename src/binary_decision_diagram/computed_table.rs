@@ -0,0 +1,67 @@
+use std::{collections::HashMap, hash::Hash, ops::Add};
+
+use super::NodeHandler;
+
+/// Hit and miss counters of the computed tables.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct CacheStatistics {
+    pub hits: usize,
+    pub misses: usize,
+}
+
+impl Add for CacheStatistics {
+    type Output = CacheStatistics;
+
+    fn add(self, rhs: Self) -> Self::Output {
+        CacheStatistics {
+            hits: self.hits + rhs.hits,
+            misses: self.misses + rhs.misses,
+        }
+    }
+}
+
+/// The results of an operation on the operands already seen, keyed by the operands.
+#[derive(Debug)]
+pub(super) struct ComputedTable<K> {
+    results: HashMap<K, NodeHandler>,
+    statistics: CacheStatistics,
+}
+
+impl<K> Default for ComputedTable<K> {
+    fn default() -> Self {
+        Self {
+            results: HashMap::default(),
+            statistics: CacheStatistics::default(),
+        }
+    }
+}
+
+impl<K> ComputedTable<K>
+where
+    K: Eq + Hash,
+{
+    pub(super) fn lookup(&mut self, key: &K) -> Option<NodeHandler> {
+        match self.results.get(key) {
+            Some(result) => {
+                self.statistics.hits += 1;
+                Some(*result)
+            }
+            None => {
+                self.statistics.misses += 1;
+                None
+            }
+        }
+    }
+
+    pub(super) fn insert(&mut self, key: K, result: NodeHandler) {
+        self.results.insert(key, result);
+    }
+
+    pub(super) fn retain(&mut self, mut predicate: impl FnMut(&K, &NodeHandler) -> bool) {
+        self.results.retain(|key, result| predicate(key, result));
+    }
+
+    pub(super) fn statistics(&self) -> CacheStatistics {
+        self.statistics
+    }
+}
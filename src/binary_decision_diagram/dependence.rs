@@ -0,0 +1,75 @@
+use std::{collections::HashMap, hash::Hash};
+
+use super::{BinaryDecisionDiagram, BinaryIndex, Element, NodeHandler};
+
+impl<T> BinaryDecisionDiagram<T>
+where
+    T: Clone + Eq + Ord + Hash,
+{
+    /// The variables taking the same value in every model of `f`, with that value, in the
+    /// variable order. An unsatisfiable `f` has none.
+    pub fn essential_variables(&self, f: NodeHandler) -> Vec<(T, bool)> {
+        let mut essential: Vec<(T, bool)> = self
+            .essential_variables_memoized(f, &mut HashMap::new())
+            .into_iter()
+            .collect();
+        essential.sort_by_key(|(variable, _)| self.levels[variable]);
+        essential
+    }
+
+    // A variable is essential to a node if one child is false and the node tests it, or if
+    // it is essential to both children with the same value
+    fn essential_variables_memoized(
+        &self,
+        f: NodeHandler,
+        memo: &mut HashMap<NodeHandler, HashMap<T, bool>>,
+    ) -> HashMap<T, bool> {
+        let variable = match f.get_element(self) {
+            Element::Variable(variable) => variable.clone(),
+            Element::Binary(_) => return HashMap::new(),
+        };
+        if let Some(essential) = memo.get(&f) {
+            return essential.clone();
+        }
+        let false_leaf = Self::get_leaf(false);
+        let (low, high) = (
+            f.get_child(self, BinaryIndex::Left).unwrap(),
+            f.get_child(self, BinaryIndex::Right).unwrap(),
+        );
+        let essential = if low == false_leaf {
+            let mut essential = self.essential_variables_memoized(high, memo);
+            essential.insert(variable, true);
+            essential
+        } else if high == false_leaf {
+            let mut essential = self.essential_variables_memoized(low, memo);
+            essential.insert(variable, false);
+            essential
+        } else {
+            let high = self.essential_variables_memoized(high, memo);
+            self.essential_variables_memoized(low, memo)
+                .into_iter()
+                .filter(|(variable, value)| high.get(variable) == Some(value))
+                .collect()
+        };
+        memo.insert(f, essential.clone());
+        essential
+    }
+}
+
+#[test]
+fn essential_variables_test() {
+    let (mut diagram, x, y, z) = super::three_variables();
+    let false_leaf = BinaryDecisionDiagram::<usize>::get_leaf(false);
+    // x & !z & (y | z), i.e. x & y & !z
+    let y_or_z = diagram.ite(y, y, z);
+    let x_and_not_z = diagram.ite(x, z.negate(), false_leaf);
+    let f = diagram.ite(x_and_not_z, y_or_z, false_leaf);
+    assert_eq!(
+        diagram.essential_variables(f),
+        vec![(0, true), (1, true), (2, false)]
+    );
+    let x_or_z = diagram.ite(x, x, z);
+    let g = diagram.ite(x_or_z, y.negate(), false_leaf);
+    assert_eq!(diagram.essential_variables(g), vec![(1, false)]);
+    assert_eq!(diagram.essential_variables(false_leaf), vec![]);
+}
@@ -0,0 +1,89 @@
+use std::{collections::HashMap, hash::Hash};
+
+use super::{BinaryDecisionDiagram, BinaryIndex, Element, NodeHandler};
+
+impl<T> BinaryDecisionDiagram<T>
+where
+    T: Clone + Eq + Ord + Hash,
+{
+    /// The value of `f` under `assignment`, following the path it takes down to a leaf, so
+    /// only the variables on that path need a value. Otherwise gives the first variable
+    /// on the path without one.
+    pub fn eval(&self, f: NodeHandler, assignment: &HashMap<T, bool>) -> Result<bool, T> {
+        let mut node = f;
+        while let Element::Variable(variable) = node.get_element(self) {
+            let index = match assignment.get(variable) {
+                Some(false) => BinaryIndex::Left,
+                Some(true) => BinaryIndex::Right,
+                None => return Err(variable.clone()),
+            };
+            node = node.get_child(self, index).unwrap();
+        }
+        Ok(node == Self::get_leaf(true))
+    }
+
+    /// The values of `f` under 64 assignments at once, the `i`th bit of the value of each
+    /// variable and of the result belonging to the `i`th assignment. Every variable `f`
+    /// depends on needs a value, otherwise gives the first one without one.
+    pub fn eval_lanes(&self, f: NodeHandler, assignments: &HashMap<T, u64>) -> Result<u64, T> {
+        self.eval_lanes_memoized(f, assignments, &mut HashMap::new())
+    }
+
+    fn eval_lanes_memoized(
+        &self,
+        f: NodeHandler,
+        assignments: &HashMap<T, u64>,
+        memo: &mut HashMap<NodeHandler, u64>,
+    ) -> Result<u64, T> {
+        let variable = match f.get_element(self) {
+            Element::Variable(variable) => variable,
+            Element::Binary(false) => return Ok(0),
+            Element::Binary(true) => return Ok(u64::MAX),
+        };
+        if f.is_complemented() {
+            return Ok(!self.eval_lanes_memoized(f.negate(), assignments, memo)?);
+        }
+        if let Some(result) = memo.get(&f) {
+            return Ok(*result);
+        }
+        let lanes = *assignments.get(variable).ok_or_else(|| variable.clone())?;
+        let (low, high) = (
+            f.get_child(self, BinaryIndex::Left).unwrap(),
+            f.get_child(self, BinaryIndex::Right).unwrap(),
+        );
+        let (low, high) = (
+            self.eval_lanes_memoized(low, assignments, memo)?,
+            self.eval_lanes_memoized(high, assignments, memo)?,
+        );
+        let result = lanes & high | !lanes & low;
+        memo.insert(f, result);
+        Ok(result)
+    }
+}
+
+#[test]
+fn eval_test() {
+    let (mut diagram, x, y, z) = super::three_variables();
+    // x ? !y : z
+    let f = diagram.ite(x, y.negate(), z);
+    assert_eq!(
+        diagram.eval(f, &HashMap::from([(0, true), (1, true)])),
+        Ok(false)
+    );
+    assert_eq!(
+        diagram.eval(f, &HashMap::from([(0, false), (2, true)])),
+        Ok(true)
+    );
+    assert_eq!(
+        diagram.eval(f, &HashMap::from([(0, false), (1, true)])),
+        Err(2)
+    );
+    // All 8 assignments in the lowest lanes, x being the highest bit of the lane number
+    let lanes = HashMap::from([(0, 0b11110000), (1, 0b11001100), (2, 0b10101010)]);
+    assert_eq!(diagram.eval_lanes(f, &lanes), Ok(0b00111010));
+    assert_eq!(diagram.eval_lanes(f.negate(), &lanes), Ok(!0b00111010));
+    assert_eq!(
+        diagram.eval_lanes(f, &HashMap::from([(0, 0), (2, 0)])),
+        Err(1)
+    );
+}
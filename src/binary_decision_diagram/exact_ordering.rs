@@ -0,0 +1,145 @@
+use std::{
+    collections::{btree_map::Entry, BTreeMap, HashMap, HashSet},
+    hash::Hash,
+};
+
+use super::{BinaryDecisionDiagram, BinaryIndex, Element, NodeHandler};
+
+/// The most variables `optimal_order` accepts, as its cost is exponential in their number
+pub const MAXIMUM_EXACT_ORDERING_VARIABLES: usize = 16;
+
+// The fewest nodes testing the variables of a set placed at the top, an order of the set
+// achieving it, and the cofactors of the root over the assignments to the set
+type Placement = (usize, Vec<usize>, HashSet<NodeHandler>);
+
+impl<T> BinaryDecisionDiagram<T>
+where
+    T: Clone + Eq + Ord + Hash,
+{
+    /// An order of the variables `root` depends on for which it has the fewest nodes, along
+    /// with that number of nodes, by the dynamic programming of Friedman and Supowit.
+    ///
+    /// With a set of variables placed at the top, whatever their order, the nodes testing the
+    /// variable right below are the distinct cofactors of `root` over the assignments to the
+    /// set that depend on it. So the best order of each set follows from the best orders of
+    /// its subsets with one variable less. Panics beyond `MAXIMUM_EXACT_ORDERING_VARIABLES`.
+    pub fn optimal_order(&mut self, root: NodeHandler) -> (Vec<T>, usize) {
+        let support = self.support(root);
+        assert!(
+            support.len() <= MAXIMUM_EXACT_ORDERING_VARIABLES,
+            "Too many variables for exact ordering"
+        );
+        // A node and its complement are the same node
+        let regular = |node: NodeHandler| match node.is_complemented() {
+            true => node.negate(),
+            false => node,
+        };
+
+        // Sets of variables are bit masks of their indices in `support`
+        let mut memo = vec![HashMap::new(); support.len()];
+        let mut layer: BTreeMap<u32, Placement> =
+            BTreeMap::from([(0, (0, vec![], HashSet::from([regular(root)])))]);
+        for _ in 0..support.len() {
+            let mut next_layer = BTreeMap::new();
+            for (set, (size, order, cofactors)) in &layer {
+                for variable in (0..support.len()).filter(|variable| set >> variable & 1 == 0) {
+                    // The cofactors of the next set are the same whichever subset they come
+                    // from, so they are only collected once
+                    let next_set = set | 1 << variable;
+                    let mut next_cofactors = match next_layer.contains_key(&next_set) {
+                        true => None,
+                        false => Some(HashSet::new()),
+                    };
+                    let mut nodes = 0;
+                    for cofactor in cofactors {
+                        let children = [false, true].map(|value| {
+                            self.cofactor(*cofactor, &support[variable], value, &mut memo[variable])
+                        });
+                        if children[0] != children[1] {
+                            nodes += 1;
+                        }
+                        if let Some(next_cofactors) = &mut next_cofactors {
+                            next_cofactors.extend(children.map(regular));
+                        }
+                    }
+                    let placement = (size + nodes, [order.as_slice(), &[variable]].concat());
+                    match next_layer.entry(next_set) {
+                        Entry::Vacant(entry) => {
+                            entry.insert((placement.0, placement.1, next_cofactors.unwrap()));
+                        }
+                        Entry::Occupied(mut entry) if entry.get().0 > placement.0 => {
+                            (entry.get_mut().0, entry.get_mut().1) = placement;
+                        }
+                        Entry::Occupied(_) => {}
+                    }
+                }
+            }
+            layer = next_layer;
+        }
+
+        let (size, order, _) = layer.into_values().next().unwrap();
+        let order = order
+            .into_iter()
+            .map(|variable| support[variable].clone())
+            .collect();
+        (order, size)
+    }
+
+    // `node` with `variable` set to `value`, memoized in `memo` for this variable
+    fn cofactor(
+        &mut self,
+        node: NodeHandler,
+        variable: &T,
+        value: bool,
+        memo: &mut HashMap<(NodeHandler, bool), NodeHandler>,
+    ) -> NodeHandler {
+        let top = match node.get_element(self) {
+            Element::Variable(top) if self.levels[top] <= self.levels[variable] => top.clone(),
+            _ => return node,
+        };
+        if top == *variable {
+            let index = match value {
+                false => BinaryIndex::Left,
+                true => BinaryIndex::Right,
+            };
+            return node.get_child(self, index).unwrap();
+        }
+        if node.is_complemented() {
+            return self.cofactor(node.negate(), variable, value, memo).negate();
+        }
+        if let Some(result) = memo.get(&(node, value)) {
+            return *result;
+        }
+        let children = (
+            node.get_child(self, BinaryIndex::Left).unwrap(),
+            node.get_child(self, BinaryIndex::Right).unwrap(),
+        );
+        let children = (
+            self.cofactor(children.0, variable, value, memo),
+            self.cofactor(children.1, variable, value, memo),
+        );
+        let result = self.add_node_if_necessary(top, children);
+        memo.insert((node, value), result);
+        result
+    }
+}
+
+#[test]
+fn optimal_order_test() {
+    let mut diagram = BinaryDecisionDiagram::default();
+    let (false_leaf, true_leaf) = BinaryDecisionDiagram::<usize>::get_leaves();
+    // x0 & x3 | x1 & x4 | x2 & x5, whose pairs are apart in the natural order
+    let mut root = false_leaf;
+    for (a, b) in [(0, 3), (1, 4), (2, 5)] {
+        let (a, b) = (diagram.add_variable(a), diagram.add_variable(b));
+        let a_and_b = diagram.ite(a, b, false_leaf);
+        root = diagram.ite(root, true_leaf, a_and_b);
+    }
+    assert_eq!(diagram.count_nodes(&[root]), 14);
+
+    let (order, size) = diagram.optimal_order(root);
+    assert_eq!(size, 6);
+    for pair in order.chunks(2) {
+        assert_eq!(pair[0] % 3, pair[1] % 3);
+    }
+}
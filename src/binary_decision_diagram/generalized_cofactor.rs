@@ -0,0 +1,156 @@
+use std::{collections::HashMap, hash::Hash};
+
+use super::{BinaryDecisionDiagram, Element, NodeHandler};
+
+impl<T> BinaryDecisionDiagram<T>
+where
+    T: Clone + Eq + Ord + Hash,
+{
+    /// The generalized cofactor of Coudert and Madre: a function that agrees with `f`
+    /// wherever the care set `care` holds, mapping every other assignment to the closest one
+    /// in `care`. It distributes over the operations, i.e. `constrain(f & g, c)` is
+    /// `constrain(f, c) & constrain(g, c)`, but may depend on variables `f` does not. Gives
+    /// false for an empty care set.
+    pub fn constrain(&mut self, f: NodeHandler, care: NodeHandler) -> NodeHandler {
+        self.constrain_memoized(f, care, &mut HashMap::new())
+    }
+
+    /// The restrict operator of Coudert and Madre: a function that agrees with `f` wherever
+    /// the care set `care` holds, chosen to have few nodes. Unlike `constrain`, it only
+    /// depends on variables `f` depends on, and the variables tested by `care` alone are
+    /// quantified away. Named after its counterpart in BuDDy, as `restrict` sets variables
+    /// to values. Gives false for an empty care set.
+    pub fn simplify(&mut self, f: NodeHandler, care: NodeHandler) -> NodeHandler {
+        self.simplify_memoized(f, care, &mut HashMap::new())
+    }
+
+    // The result for the basic cases of both operators
+    fn generalized_cofactor_basic_case(
+        &self,
+        f: NodeHandler,
+        care: NodeHandler,
+    ) -> Option<NodeHandler> {
+        let (false_leaf, true_leaf) = Self::get_leaves();
+        match () {
+            _ if care == false_leaf => Some(false_leaf),
+            _ if care == true_leaf || f.is_leaf() => Some(f),
+            _ if f == care => Some(true_leaf),
+            _ if f == care.negate() => Some(false_leaf),
+            _ => None,
+        }
+    }
+
+    fn top_variable(&self, f: NodeHandler, g: NodeHandler) -> T {
+        let top = match self.level_of(f) <= self.level_of(g) {
+            true => f,
+            false => g,
+        };
+        match top.get_element(self) {
+            Element::Variable(variable) => variable.clone(),
+            Element::Binary(_) => unreachable!(),
+        }
+    }
+
+    fn constrain_memoized(
+        &mut self,
+        f: NodeHandler,
+        care: NodeHandler,
+        memo: &mut HashMap<(NodeHandler, NodeHandler), NodeHandler>,
+    ) -> NodeHandler {
+        if let Some(result) = self.generalized_cofactor_basic_case(f, care) {
+            return result;
+        }
+        // The result for the negation is the negation of the result
+        if f.is_complemented() {
+            return self.constrain_memoized(f.negate(), care, memo).negate();
+        }
+        if let Some(result) = memo.get(&(f, care)) {
+            return *result;
+        }
+
+        let false_leaf = Self::get_leaf(false);
+        let variable = self.top_variable(f, care);
+        let ((f0, f1), (c0, c1)) = (
+            self.cofactors(f, &variable),
+            self.cofactors(care, &variable),
+        );
+        let result = match (c0 == false_leaf, c1 == false_leaf) {
+            (true, _) => self.constrain_memoized(f1, c1, memo),
+            (_, true) => self.constrain_memoized(f0, c0, memo),
+            _ => {
+                let children = (
+                    self.constrain_memoized(f0, c0, memo),
+                    self.constrain_memoized(f1, c1, memo),
+                );
+                self.add_node_if_necessary(variable, children)
+            }
+        };
+        memo.insert((f, care), result);
+        result
+    }
+
+    fn simplify_memoized(
+        &mut self,
+        f: NodeHandler,
+        care: NodeHandler,
+        memo: &mut HashMap<(NodeHandler, NodeHandler), NodeHandler>,
+    ) -> NodeHandler {
+        if let Some(result) = self.generalized_cofactor_basic_case(f, care) {
+            return result;
+        }
+        if f.is_complemented() {
+            return self.simplify_memoized(f.negate(), care, memo).negate();
+        }
+        if let Some(result) = memo.get(&(f, care)) {
+            return *result;
+        }
+
+        let (false_leaf, true_leaf) = Self::get_leaves();
+        let variable = self.top_variable(f, care);
+        let (c0, c1) = self.cofactors(care, &variable);
+        let result = if self.level_of(care) < self.level_of(f) {
+            // `f` does not depend on the variable, so neither does the result
+            let care = self.ite(c0, true_leaf, c1);
+            self.simplify_memoized(f, care, memo)
+        } else {
+            let (f0, f1) = self.cofactors(f, &variable);
+            match (c0 == false_leaf, c1 == false_leaf) {
+                (true, _) => self.simplify_memoized(f1, c1, memo),
+                (_, true) => self.simplify_memoized(f0, c0, memo),
+                _ => {
+                    let children = (
+                        self.simplify_memoized(f0, c0, memo),
+                        self.simplify_memoized(f1, c1, memo),
+                    );
+                    self.add_node_if_necessary(variable, children)
+                }
+            }
+        };
+        memo.insert((f, care), result);
+        result
+    }
+}
+
+#[test]
+fn generalized_cofactor_test() {
+    let (mut diagram, x, y, z) = super::three_variables();
+    let (false_leaf, true_leaf) = BinaryDecisionDiagram::<usize>::get_leaves();
+    // x & y & z where x & y holds is z
+    let x_and_y = diagram.ite(x, y, false_leaf);
+    let f = diagram.ite(x_and_y, z, false_leaf);
+    assert_eq!(diagram.constrain(f, x_and_y), z);
+    assert_eq!(diagram.simplify(f, x_and_y), z);
+    // x ^ y where x <-> z holds: constrain may bring in z, simplify may not
+    let x_xor_y = diagram.ite(x, y.negate(), y);
+    let x_iff_z = diagram.ite(x, z, z.negate());
+    let constrained = diagram.constrain(x_xor_y, x_iff_z);
+    let simplified = diagram.simplify(x_xor_y, x_iff_z);
+    for result in [constrained, simplified] {
+        let agreement = diagram.ite(result, x_xor_y, x_xor_y.negate());
+        assert_eq!(diagram.ite(x_iff_z, agreement, true_leaf), true_leaf);
+    }
+    assert!(!diagram.support(simplified).contains(&2));
+    // A care set testing variables below `f` only is quantified away
+    assert_eq!(diagram.simplify(x, z), x);
+    assert_eq!(diagram.constrain(x, false_leaf), false_leaf);
+}
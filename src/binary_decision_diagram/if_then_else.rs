@@ -0,0 +1,130 @@
+use std::hash::Hash;
+
+use super::{BinaryDecisionDiagram, BinaryIndex, Element, NodeHandler};
+
+impl<T> BinaryDecisionDiagram<T>
+where
+    T: Clone + Eq + Ord + Hash,
+{
+    /// The formula `(f & g) | (!f & h)`, i.e. `g` if `f` holds and `h` otherwise.
+    ///
+    /// Every other operation is expressed with it. The operands are first brought into a
+    /// standard triple, so that equivalent calls such as `ite(f, g, F)` and `ite(g, f, F)`
+    /// share one entry in the computed table.
+    pub fn ite(&mut self, f: NodeHandler, g: NodeHandler, h: NodeHandler) -> NodeHandler {
+        let (false_leaf, true_leaf) = Self::get_leaves();
+
+        // Replace the operands equal or complementary to `f` with constants
+        let g = match g {
+            g if g == f => true_leaf,
+            g if g == f.negate() => false_leaf,
+            g => g,
+        };
+        let h = match h {
+            h if h == f => false_leaf,
+            h if h == f.negate() => true_leaf,
+            h => h,
+        };
+
+        // Basic case
+        if f == true_leaf || g == h {
+            return g;
+        }
+        if f == false_leaf {
+            return h;
+        }
+        if g == true_leaf && h == false_leaf {
+            return f;
+        }
+        if g == false_leaf && h == true_leaf {
+            return f.negate();
+        }
+
+        // Among the equivalent triples, put the smaller handler first
+        let (f, g, h) = match (g, h) {
+            // ite(f, T, h) = ite(h, T, f)
+            (g, h) if g == true_leaf && h < f => (h, g, f),
+            // ite(f, g, F) = ite(g, f, F)
+            (g, h) if h == false_leaf && g < f => (g, f, h),
+            // ite(f, g, T) = ite(!g, !f, T)
+            (g, h) if h == true_leaf && g.negate() < f => (g.negate(), f.negate(), h),
+            // ite(f, F, h) = ite(!h, F, !f)
+            (g, h) if g == false_leaf && h.negate() < f => (h.negate(), g, f.negate()),
+            // ite(f, g, !g) = ite(g, f, !f)
+            (g, h) if h == g.negate() && g < f => (g, f, f.negate()),
+            _ => (f, g, h),
+        };
+        // ite(!f, g, h) = ite(f, h, g)
+        let (f, g, h) = match f.is_complemented() {
+            true => (f.negate(), h, g),
+            false => (f, g, h),
+        };
+        // ite(f, !g, !h) = !ite(f, g, h)
+        let (g, h, complemented) = match g.is_complemented() {
+            true => (g.negate(), h.negate(), true),
+            false => (g, h, false),
+        };
+
+        let result = match self.if_then_else_table.lookup(&(f, g, h)) {
+            Some(result) => result,
+            None => {
+                // The variable closest to the root
+                let variable = [f, g, h]
+                    .iter()
+                    .filter_map(|operand| match operand.get_element(self) {
+                        Element::Variable(variable) => Some(variable),
+                        Element::Binary(_) => None,
+                    })
+                    .min_by_key(|variable| self.levels[*variable])
+                    .unwrap()
+                    .clone();
+                let ((f0, f1), (g0, g1), (h0, h1)) = (
+                    self.cofactors(f, &variable),
+                    self.cofactors(g, &variable),
+                    self.cofactors(h, &variable),
+                );
+                let children = (self.ite(f0, g0, h0), self.ite(f1, g1, h1));
+                let result = self.add_node_if_necessary(variable, children);
+                self.if_then_else_table.insert((f, g, h), result);
+                result
+            }
+        };
+        match complemented {
+            true => result.negate(),
+            false => result,
+        }
+    }
+
+    // The (left, right) children of `node` if it tests `variable`, otherwise `node` itself twice
+    pub(super) fn cofactors(&self, node: NodeHandler, variable: &T) -> (NodeHandler, NodeHandler) {
+        if node.get_element(self) == Element::Variable(variable) {
+            (
+                node.get_child(self, BinaryIndex::Left).unwrap(),
+                node.get_child(self, BinaryIndex::Right).unwrap(),
+            )
+        } else {
+            (node, node)
+        }
+    }
+}
+
+#[test]
+fn if_then_else_test() {
+    let mut diagram = BinaryDecisionDiagram::default();
+    let (c, a, b) = (
+        diagram.add_variable(0),
+        diagram.add_variable(1),
+        diagram.add_variable(2),
+    );
+    let multiplexer = diagram.ite(c, a, b);
+    assert_eq!(multiplexer.get_child(&diagram, BinaryIndex::Left), Some(b));
+    assert_eq!(multiplexer.get_child(&diagram, BinaryIndex::Right), Some(a));
+
+    let (false_leaf, true_leaf) = BinaryDecisionDiagram::<usize>::get_leaves();
+    let a_and_b = diagram.ite(a, b, false_leaf);
+    assert_eq!(diagram.ite(b, a, false_leaf), a_and_b);
+    assert_eq!(
+        diagram.ite(a.negate(), true_leaf, b.negate()),
+        a_and_b.negate()
+    );
+}
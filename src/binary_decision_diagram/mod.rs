@@ -1,59 +1,135 @@
 pub mod binary_index;
+mod computed_table;
+mod dependence;
+mod evaluation;
+mod exact_ordering;
+mod generalized_cofactor;
+mod if_then_else;
+mod model_counting;
 pub mod node_handler;
-use std::collections::HashSet;
+mod quantification;
+mod reordering;
+mod restriction;
+mod substitution;
+mod witness;
+use std::{
+    collections::{HashMap, HashSet},
+    hash::Hash,
+    sync::atomic::{self, AtomicU32},
+};
 
-use crate::utility::*;
+use crate::unwrap;
 pub use binary_index::*;
+pub use computed_table::CacheStatistics;
+use computed_table::*;
+pub use exact_ordering::MAXIMUM_EXACT_ORDERING_VARIABLES;
 pub use node_handler::*;
 
-type NodePtrMut<T> = *mut Node<T>;
+// Each diagram gets a distinct id, which is recorded in its node ids
+// so that a node handler cannot be used with another diagram
+static NEXT_DIAGRAM_ID: AtomicU32 = AtomicU32::new(0);
 
-#[allow(clippy::derive_hash_xor_eq)]
-#[derive(Clone, Copy, Hash, Debug, Eq)]
-enum Link<T>
-where
-    T: Clone,
-{
-    Node(NodePtrMut<T>),
+#[derive(Clone, Copy, Hash, Debug, PartialEq, Eq, PartialOrd, Ord)]
+struct NodeId {
+    diagram: u32,
+    index: u32,
+}
+
+// An edge of the diagram. `Node(id, true)` is a complement edge, which stands for the
+// negation of the node it points to. In the same way, there is only one leaf, `true`,
+// and `false` is its complement.
+#[derive(Clone, Copy, Hash, Debug, PartialEq, Eq, PartialOrd, Ord)]
+enum Link {
+    Node(NodeId, bool),
     Leaf(bool),
 }
 
-impl<T> PartialEq for Link<T>
-where
-    T: Clone,
-{
-    fn eq(&self, other: &Self) -> bool {
-        match (self, other) {
-            (Self::Node(l0), Self::Node(r0)) => l0 == r0,
-            (Self::Leaf(l0), Self::Leaf(r0)) => l0 == r0,
-            _ => false,
+impl Link {
+    fn negate(self) -> Self {
+        match self {
+            Link::Node(node, complemented) => Link::Node(node, !complemented),
+            Link::Leaf(value) => Link::Leaf(!value),
         }
     }
+
+    fn is_complemented(self) -> bool {
+        matches!(self, Link::Node(_, true) | Link::Leaf(false))
+    }
 }
 
+// The nodes testing one variable, indexed by their (left, right) links
+type Subtable = HashMap<(Link, Link), NodeId>;
+
+#[derive(Debug)]
 struct Node<T>
 where
     T: Clone,
 {
     variable: T,
-    links: (Link<T>, Link<T>),
+    links: (Link, Link),
 
-    // (self as the left child of, self as the right child of)
-    parents: (HashSet<NodePtrMut<T>>, HashSet<NodePtrMut<T>>),
+    // Number of parent nodes plus external references
+    references: usize,
 }
 
-#[derive(Debug, Default)]
+/// Number of nodes above which `collect_garbage_if_necessary` starts collecting
+const INITIAL_GARBAGE_COLLECTION_THRESHOLD: usize = 1 << 12;
+
+#[derive(Debug)]
 pub struct BinaryDecisionDiagram<T>
 where
     T: Clone,
 {
-    roots: HashSet<NodePtrMut<T>>, // Maybe just for destruction use
+    id: u32,
+
+    // Node storage, indexed by `NodeId::index`. Slots of collected nodes are `None`
+    // until they are reused.
+    nodes: Vec<Option<Node<T>>>,
+    free_slots: Vec<u32>,
+
+    // Every node of the diagram, grouped by variable
+    unique_table: HashMap<T, Subtable>,
+
+    // The variables from the root down, and the level of each of them in this order
+    order: Vec<T>,
+    levels: HashMap<T, usize>,
 
-    // (
-    //     (`false` as left child of, `false` as right child of), 
-    //     (`true` as left child of,  `true` as right child of)
-    // )
-    leaf_parents: ((HashSet<NodePtrMut<T>>, HashSet<NodePtrMut<T>>), (HashSet<NodePtrMut<T>>, HashSet<NodePtrMut<T>>)),
+    // The groups of variables reordering keeps together, and the group of each variable
+    group_members: Vec<Vec<T>>,
+    groups: HashMap<T, usize>,
+
+    // (if, then, else) -> result
+    if_then_else_table: ComputedTable<(NodeHandler, NodeHandler, NodeHandler)>,
+    // (formula, cube of the quantified variables) -> result
+    exists_table: ComputedTable<(NodeHandler, NodeHandler)>,
+    // (f, g, cube of the quantified variables) -> result of `exists cube. f & g`
+    and_exists_table: ComputedTable<(NodeHandler, NodeHandler, NodeHandler)>,
+
+    garbage_collection_threshold: Option<usize>,
+    reordering_threshold: Option<usize>,
+}
+
+impl<T> Default for BinaryDecisionDiagram<T>
+where
+    T: Clone,
+{
+    fn default() -> Self {
+        Self {
+            id: NEXT_DIAGRAM_ID.fetch_add(1, atomic::Ordering::Relaxed),
+            nodes: Vec::default(),
+            free_slots: Vec::default(),
+            unique_table: HashMap::default(),
+            order: Vec::default(),
+            levels: HashMap::default(),
+            group_members: Vec::default(),
+            groups: HashMap::default(),
+            if_then_else_table: ComputedTable::default(),
+            exists_table: ComputedTable::default(),
+            and_exists_table: ComputedTable::default(),
+            garbage_collection_threshold: Some(INITIAL_GARBAGE_COLLECTION_THRESHOLD),
+            reordering_threshold: None,
+        }
+    }
 }
 
 // For basic operations
@@ -61,67 +137,167 @@ impl<T> BinaryDecisionDiagram<T>
 where
     T: Clone,
 {
-    fn add_parent_for_node(
-        &mut self,
-        node: NodePtrMut<T>,
-        parent: *const Node<T>,
-        binary_index: BinaryIndex,
-    ) {
-        if self.roots.contains(&node) {
-            self.roots.remove(&node);
-        }
-        match binary_index {
-            BinaryIndex::Left => unsafe { &mut *node }
-                .parents
-                .0
-                .insert(parent as NodePtrMut<T>),
-            BinaryIndex::Right => unsafe { &mut *node }
-                .parents
-                .1
-                .insert(parent as NodePtrMut<T>),
+    fn node(&self, id: NodeId) -> &Node<T> {
+        assert_eq!(
+            id.diagram, self.id,
+            "The node handler belongs to another diagram"
+        );
+        self.nodes[id.index as usize]
+            .as_ref()
+            .expect("The node has been collected as garbage")
+    }
+
+    fn node_mut(&mut self, id: NodeId) -> &mut Node<T> {
+        assert_eq!(
+            id.diagram, self.id,
+            "The node handler belongs to another diagram"
+        );
+        self.nodes[id.index as usize]
+            .as_mut()
+            .expect("The node has been collected as garbage")
+    }
+
+    fn add_node(&mut self, variable: T, children: (NodeHandler, NodeHandler)) -> NodeHandler
+    where
+        T: Eq + Ord + Hash,
+    {
+        if !self.levels.contains_key(&variable) {
+            self.add_level(variable.clone());
+        }
+        self.increase_references(children.0 .0);
+        self.increase_references(children.1 .0);
+        let node = Node {
+            variable: variable.clone(),
+            links: (children.0 .0, children.1 .0),
+            references: 0,
+        };
+        let index = match self.free_slots.pop() {
+            Some(index) => {
+                self.nodes[index as usize] = Some(node);
+                index
+            }
+            None => {
+                self.nodes.push(Some(node));
+                (self.nodes.len() - 1) as u32
+            }
         };
+        let new_node = NodeId {
+            diagram: self.id,
+            index,
+        };
+        self.unique_table
+            .entry(variable)
+            .or_default()
+            .insert((children.0 .0, children.1 .0), new_node);
+        NodeHandler(Link::Node(new_node, false))
     }
 
-    fn add_node(
-        &mut self,
-        variable: T,
-        children: (NodeHandler<T>, NodeHandler<T>),
-    ) -> NodeHandler<T> {
-        let new_node: NodePtrMut<T> = unsafe { allocate() };
-        unsafe {
-            std::ptr::write(
-                new_node,
-                Node {
-                    variable,
-                    links: (children.0 .0.clone(), children.1 .0.clone()),
-                    parents: (HashSet::default(), HashSet::default()),
-                },
-            );
-        }
-        self.roots.insert(new_node);
-        match children.0 {
-            NodeHandler(Link::Node(left)) => self.add_parent_for_node(left, new_node, BinaryIndex::Left),
-            NodeHandler(Link::Leaf(value)) => match value {
-                true => {
-                    self.leaf_parents.1.0.insert(new_node);
-                }
-                false => {
-                    self.leaf_parents.0.0.insert(new_node);
-                }
-            },
+    // Places a new variable right above the smallest greater variable, or at the bottom if
+    // there is none, so that variables keep their natural order until they are reordered.
+    // It goes below the group it would otherwise split.
+    fn add_level(&mut self, variable: T)
+    where
+        T: Eq + Ord + Hash,
+    {
+        let mut level = self
+            .order
+            .iter()
+            .filter(|other| **other > variable)
+            .min()
+            .map_or(self.order.len(), |other| self.levels[other]);
+        let group = |level: usize| {
+            self.order
+                .get(level)
+                .and_then(|other| self.groups.get(other))
+        };
+        while level > 0 && group(level).is_some() && group(level) == group(level - 1) {
+            level += 1;
         }
-        match children.1 {
-            NodeHandler(Link::Node(right)) => self.add_parent_for_node(right, new_node, BinaryIndex::Right),
-            NodeHandler(Link::Leaf(value)) => match value {
-                true => {
-                    self.leaf_parents.1.1.insert(new_node);
-                }
-                false => {
-                    self.leaf_parents.0.1.insert(new_node);
+        self.unique_table.entry(variable.clone()).or_default();
+        self.order.insert(level, variable);
+        for (level, variable) in self.order.iter().enumerate().skip(level) {
+            self.levels.insert(variable.clone(), level);
+        }
+    }
+
+    fn increase_references(&mut self, link: Link) {
+        if let Link::Node(node, _) = link {
+            self.node_mut(node).references += 1;
+        }
+    }
+
+    // Returns whether the node becomes unreferenced
+    fn decrease_references(&mut self, link: Link) -> bool {
+        match link {
+            Link::Node(node, _) => {
+                let node = self.node_mut(node);
+                node.references -= 1;
+                node.references == 0
+            }
+            Link::Leaf(_) => false,
+        }
+    }
+
+    // Frees `node`, which has become unreferenced, then the children it leaves unreferenced
+    fn free(&mut self, node: NodeId, freed: &mut HashSet<NodeId>)
+    where
+        T: Eq + Hash,
+    {
+        let mut garbage = vec![node];
+        while let Some(node) = garbage.pop() {
+            let Node {
+                variable, links, ..
+            } = self.nodes[node.index as usize].take().unwrap();
+            self.free_slots.push(node.index);
+            self.unique_table
+                .get_mut(&variable)
+                .unwrap()
+                .remove(&(links.0, links.1));
+            for link in [links.0, links.1] {
+                if self.decrease_references(link) {
+                    garbage.push(unwrap!(link, Link::Node(child, _), child));
                 }
-            },
+            }
+            freed.insert(node);
         }
-        NodeHandler(Link::Node(new_node))
+    }
+
+    // Forgets the results involving freed nodes as their slots may be reused
+    fn forget_freed(&mut self, freed: &HashSet<NodeId>) {
+        let is_freed =
+            |node: &NodeHandler| matches!(node.0, Link::Node(node, _) if freed.contains(&node));
+        self.if_then_else_table.retain(|(f, g, h), result| {
+            !is_freed(f) && !is_freed(g) && !is_freed(h) && !is_freed(result)
+        });
+        self.exists_table
+            .retain(|(f, cube), result| !is_freed(f) && !is_freed(cube) && !is_freed(result));
+        self.and_exists_table.retain(|(f, g, cube), result| {
+            !is_freed(f) && !is_freed(g) && !is_freed(cube) && !is_freed(result)
+        });
+    }
+
+    // The level of the variable `node` tests, below every variable for a leaf
+    fn level_of(&self, node: NodeHandler) -> usize
+    where
+        T: Eq + Hash,
+    {
+        match node.0 {
+            Link::Node(node, _) => self.levels[&self.node(node).variable],
+            Link::Leaf(_) => usize::MAX,
+        }
+    }
+
+    // The level of the deepest of `variables`, below which an operation on them leaves
+    // every node as it is. The variables not in the diagram are left out, as no node depends
+    // on them, so it is `None` if none of them is in the diagram.
+    fn deepest_level<'a>(&self, variables: impl IntoIterator<Item = &'a T>) -> Option<usize>
+    where
+        T: Eq + Hash + 'a,
+    {
+        variables
+            .into_iter()
+            .filter_map(|variable| self.level(variable))
+            .max()
     }
 }
 
@@ -133,46 +309,217 @@ where
     pub fn add_node_if_necessary(
         &mut self,
         variable: T,
-        children: (NodeHandler<T>, NodeHandler<T>),
-    ) -> NodeHandler<T>
+        children: (NodeHandler, NodeHandler),
+    ) -> NodeHandler
     where
-        T: Eq,
+        T: Eq + Ord + Hash,
     {
         // For node that has 2 same children
         if children.0 == children.1 {
             return children.0;
         }
-        let common_parent_sets =
-            HashSet::intersection(children.0.get_parents(self, BinaryIndex::Left), children.1.get_parents(self, BinaryIndex::Right));
-        let mut candidate = Vec::<NodeHandler<T>>::new();
-        for node in common_parent_sets {
-            let node = unsafe { &mut **node };
-            if node.variable == variable {
-                candidate.push(NodeHandler(Link::Node(node)));
+        // Only the left link may be a complement edge, so that every function has a unique
+        // representation: otherwise build the negation and return a complement edge to it
+        if children.1 .0.is_complemented() {
+            return self
+                .add_node_if_necessary(variable, (children.0.negate(), children.1.negate()))
+                .negate();
+        }
+        // For node isomorphic to an existing one
+        let links = (children.0 .0, children.1 .0);
+        if let Some(node) = self
+            .unique_table
+            .get(&variable)
+            .and_then(|subtable| subtable.get(&links))
+        {
+            return NodeHandler(Link::Node(*node, false));
+        }
+        self.add_node(variable, children)
+    }
+
+    /// The number of nodes in the diagram, including the unreferenced ones not collected yet.
+    pub fn node_count(&self) -> usize {
+        self.unique_table.values().map(Subtable::len).sum()
+    }
+
+    /// The number of nodes reachable from `roots`, each counted once.
+    pub fn count_nodes(&self, roots: &[NodeHandler]) -> usize {
+        let mut visited = HashSet::new();
+        let mut stack: Vec<Link> = roots.iter().map(|root| root.0).collect();
+        while let Some(link) = stack.pop() {
+            if let Link::Node(node, _) = link {
+                if visited.insert(node) {
+                    let links = self.node(node).links;
+                    stack.extend([links.0, links.1]);
+                }
             }
         }
-        if candidate.len() > 1 {
-            panic!()
+        visited.len()
+    }
+
+    /// The variables `root` depends on, from the root down.
+    pub fn support(&self, root: NodeHandler) -> Vec<T>
+    where
+        T: Eq + Hash,
+    {
+        let mut variables = HashSet::new();
+        let mut visited = HashSet::new();
+        let mut stack = vec![root.0];
+        while let Some(link) = stack.pop() {
+            if let Link::Node(node, _) = link {
+                if visited.insert(node) {
+                    let node = self.node(node);
+                    variables.insert(&node.variable);
+                    stack.extend([node.links.0, node.links.1]);
+                }
+            }
         }
-        if candidate.len() == 1 {
-            return candidate.first().unwrap().clone();
+        self.order
+            .iter()
+            .filter(|variable| variables.contains(variable))
+            .cloned()
+            .collect()
+    }
+
+    /// Marks `node` as used from outside the diagram, so that it and its descendants
+    /// survive garbage collection until a matching `dereference`.
+    pub fn reference(&mut self, node: &NodeHandler) {
+        self.increase_references(node.0);
+    }
+
+    pub fn dereference(&mut self, node: &NodeHandler) {
+        self.decrease_references(node.0);
+    }
+
+    /// Frees every node that is neither referenced nor reachable from a referenced node.
+    /// Returns the number of nodes freed.
+    ///
+    /// Handles to the freed nodes become dangling, so reference every handle still in use first.
+    pub fn collect_garbage(&mut self) -> usize
+    where
+        T: Eq + Hash,
+    {
+        let garbage: Vec<NodeId> = self
+            .unique_table
+            .values()
+            .flat_map(Subtable::values)
+            .filter(|node| self.node(**node).references == 0)
+            .copied()
+            .collect();
+        let mut freed = HashSet::new();
+        for node in garbage {
+            self.free(node, &mut freed);
+        }
+        self.forget_freed(&freed);
+        freed.len()
+    }
+
+    /// Sets the node count above which `collect_garbage_if_necessary` collects garbage.
+    /// `None` disables automatic garbage collection.
+    pub fn set_garbage_collection_threshold(&mut self, threshold: Option<usize>) {
+        self.garbage_collection_threshold = threshold;
+    }
+
+    /// Collects garbage if the diagram has grown beyond the threshold. The threshold is
+    /// raised afterwards if most nodes are still alive, to avoid collecting over and over.
+    ///
+    /// Only call it when every handle still in use is referenced.
+    pub fn collect_garbage_if_necessary(&mut self)
+    where
+        T: Eq + Hash,
+    {
+        if let Some(threshold) = self.garbage_collection_threshold {
+            if self.node_count() > threshold {
+                self.collect_garbage();
+                self.garbage_collection_threshold =
+                    Some(usize::max(threshold, 2 * self.node_count()));
+            }
         }
-        self.add_node(variable, children)
     }
 
-    pub fn get_leaf(value: bool) -> NodeHandler<T> {
+    pub fn get_leaf(value: bool) -> NodeHandler {
         NodeHandler(Link::Leaf(value))
     }
 
-    pub fn get_leaves() -> (NodeHandler<T>, NodeHandler<T>) {
+    pub fn get_leaves() -> (NodeHandler, NodeHandler) {
         (Self::get_leaf(false), Self::get_leaf(true))
     }
 
-    pub fn add_variable(&mut self, variable: T) -> NodeHandler<T>
+    pub fn add_variable(&mut self, variable: T) -> NodeHandler
     where
-        T: Eq,
+        T: Eq + Ord + Hash,
     {
         let leaves = Self::get_leaves();
         self.add_node_if_necessary(variable, leaves)
     }
+
+    /// The variables of the diagram from the root down.
+    pub fn variable_order(&self) -> &[T] {
+        &self.order
+    }
+
+    /// The level of `variable`, 0 being the closest to the root, if it is in the diagram.
+    pub fn level(&self, variable: &T) -> Option<usize>
+    where
+        T: Eq + Hash,
+    {
+        self.levels.get(variable).copied()
+    }
+
+    pub fn cache_statistics(&self) -> CacheStatistics {
+        self.if_then_else_table.statistics()
+            + self.exists_table.statistics()
+            + self.and_exists_table.statistics()
+    }
+}
+
+// A diagram of the variables 0, 1 and 2, with the node testing each
+#[cfg(test)]
+fn three_variables() -> (
+    BinaryDecisionDiagram<usize>,
+    NodeHandler,
+    NodeHandler,
+    NodeHandler,
+) {
+    let mut diagram = BinaryDecisionDiagram::default();
+    let (x, y, z) = (
+        diagram.add_variable(0),
+        diagram.add_variable(1),
+        diagram.add_variable(2),
+    );
+    (diagram, x, y, z)
+}
+
+#[test]
+fn unique_table_test() {
+    let mut diagram = BinaryDecisionDiagram::<usize>::default();
+    let leaves = BinaryDecisionDiagram::<usize>::get_leaves();
+    let first = diagram.add_node_if_necessary(0, leaves);
+    let second = diagram.add_node_if_necessary(0, leaves);
+    assert_eq!(first, second);
+    assert_eq!(diagram.node_count(), 1);
+}
+
+#[test]
+fn garbage_collection_test() {
+    let mut diagram = BinaryDecisionDiagram::<usize>::default();
+    let (false_leaf, true_leaf) = BinaryDecisionDiagram::<usize>::get_leaves();
+    diagram.add_node_if_necessary(0, (false_leaf, true_leaf));
+    let y = diagram.add_node_if_necessary(1, (false_leaf, true_leaf));
+    let x_and_y = diagram.add_node_if_necessary(0, (false_leaf, y));
+    diagram.reference(&x_and_y);
+    assert_eq!(diagram.collect_garbage(), 1);
+    assert_eq!(diagram.node_count(), 2);
+    diagram.dereference(&x_and_y);
+    assert_eq!(diagram.collect_garbage(), 2);
+    assert_eq!(diagram.node_count(), 0);
+}
+
+#[test]
+#[should_panic(expected = "another diagram")]
+fn foreign_node_test() {
+    let mut first = BinaryDecisionDiagram::<usize>::default();
+    let second = BinaryDecisionDiagram::<usize>::default();
+    let x = first.add_variable(0);
+    x.get_child(&second, BinaryIndex::Left);
 }
@@ -0,0 +1,98 @@
+use std::{collections::HashMap, hash::Hash};
+
+use super::{BinaryDecisionDiagram, BinaryIndex, NodeHandler};
+use crate::BigUnsigned;
+
+impl<T> BinaryDecisionDiagram<T>
+where
+    T: Clone + Eq + Ord + Hash,
+{
+    /// The number of assignments to `variables` satisfying `f`. The variables not in the
+    /// diagram count as free ones. Panics if `f` depends on a variable not among them.
+    pub fn sat_count(&self, f: NodeHandler, variables: &[T]) -> BigUnsigned {
+        let mut levels: Vec<usize> = variables
+            .iter()
+            .filter_map(|variable| self.level(variable))
+            .collect();
+        levels.sort_unstable();
+        levels.dedup();
+        let mut unknown: Vec<&T> = variables
+            .iter()
+            .filter(|variable| self.level(variable).is_none())
+            .collect();
+        unknown.sort_unstable();
+        unknown.dedup();
+        assert!(
+            self.support(f)
+                .iter()
+                .all(|variable| levels.binary_search(&self.levels[variable]).is_ok()),
+            "The formula depends on a variable that is not counted"
+        );
+        let count = self.sat_count_below(f, &levels, &mut HashMap::new());
+        // The counted variables above the root are free
+        count << (self.rank(f, &levels) + unknown.len())
+    }
+
+    // The number of counted variables above `node`, i.e. its position among them. Leaves
+    // come after all of them.
+    fn rank(&self, node: NodeHandler, levels: &[usize]) -> usize {
+        let level = self.level_of(node);
+        levels.partition_point(|counted| *counted < level)
+    }
+
+    // The number of assignments to the counted variables from the one of `f` down satisfying
+    // `f`. A variable skipped between a node and its child doubles the count of the child.
+    fn sat_count_below(
+        &self,
+        f: NodeHandler,
+        levels: &[usize],
+        memo: &mut HashMap<NodeHandler, BigUnsigned>,
+    ) -> BigUnsigned {
+        if f.is_leaf() {
+            return BigUnsigned::from((f == Self::get_leaf(true)) as u64);
+        }
+        // The assignments falsifying `f` are the ones satisfying its negation
+        if f.is_complemented() {
+            let all = BigUnsigned::from(1) << (levels.len() - self.rank(f, levels));
+            return all - self.sat_count_below(f.negate(), levels, memo);
+        }
+        if let Some(count) = memo.get(&f) {
+            return count.clone();
+        }
+        let rank = self.rank(f, levels);
+        let count = [BinaryIndex::Left, BinaryIndex::Right]
+            .into_iter()
+            .map(|index| {
+                let child = f.get_child(self, index).unwrap();
+                self.sat_count_below(child, levels, memo) << (self.rank(child, levels) - rank - 1)
+            })
+            .fold(BigUnsigned::default(), |sum, count| sum + count);
+        memo.insert(f, count.clone());
+        count
+    }
+}
+
+#[test]
+fn sat_count_test() {
+    let mut diagram = BinaryDecisionDiagram::default();
+    let variables: Vec<NodeHandler> = (0..3).map(|i| diagram.add_variable(i)).collect();
+    let (x, z) = (variables[0], variables[2]);
+    let (false_leaf, true_leaf) = BinaryDecisionDiagram::<usize>::get_leaves();
+    // x | z skips y, and its negation is only complemented
+    let x_or_z = diagram.ite(x, true_leaf, z);
+    assert_eq!(diagram.sat_count(x_or_z, &[0, 1, 2]).to_u64(), Some(6));
+    assert_eq!(
+        diagram.sat_count(x_or_z.negate(), &[0, 1, 2]).to_u64(),
+        Some(2)
+    );
+    assert_eq!(diagram.sat_count(x_or_z, &[2, 0]).to_u64(), Some(3));
+    assert_eq!(diagram.sat_count(z, &[0, 1, 2, 2]).to_u64(), Some(4));
+    assert_eq!(diagram.sat_count(false_leaf, &[0]).to_u64(), Some(0));
+    // Unknown variables are free, well beyond 64 of them
+    let many: Vec<usize> = (0..100).collect();
+    assert_eq!(
+        diagram.sat_count(true_leaf, &many),
+        BigUnsigned::from(1) << 100
+    );
+    assert_eq!(diagram.sat_count(x_or_z, &many), BigUnsigned::from(3) << 98);
+}
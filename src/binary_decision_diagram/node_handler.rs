@@ -1,16 +1,9 @@
-use std::{collections::HashMap, fmt::Display};
+use super::{binary_index::*, BinaryDecisionDiagram};
 
-use crate::unwrap;
-
-use super::{
-    binary_index::{self, *},
-    BinaryDecisionDiagram, NodePtrMut,
-};
-
-#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
-pub struct NodeHandler<T>(pub(super) super::Link<T>)
-where
-    T: Clone;
+/// A node of a `BinaryDecisionDiagram`. The handler is only meaningful to the diagram
+/// it comes from; using it with another diagram panics.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct NodeHandler(pub(super) super::Link);
 
 #[derive(Copy, Clone, Hash, PartialEq, Eq, PartialOrd, Ord, Debug)]
 pub enum Element<T> {
@@ -18,179 +11,54 @@ pub enum Element<T> {
     Binary(bool),
 }
 
-impl<T> NodeHandler<T>
-where
-    T: Clone,
-{
-    pub fn get_child(&self, child_index: BinaryIndex) -> Option<NodeHandler<T>> {
-        match &self.0 {
-            super::Link::Node(node) => unsafe {
-                Some(match child_index {
-                    BinaryIndex::Left => NodeHandler((*(*node)).links.0.clone()),
-                    BinaryIndex::Right => NodeHandler((*(*node)).links.1.clone()),
-                })
-            },
+impl NodeHandler {
+    pub fn get_child<T>(
+        &self,
+        diagram: &BinaryDecisionDiagram<T>,
+        child_index: BinaryIndex,
+    ) -> Option<NodeHandler>
+    where
+        T: Clone,
+    {
+        match self.0 {
+            super::Link::Node(node, complemented) => {
+                let links = diagram.node(node).links;
+                let child = match child_index {
+                    BinaryIndex::Left => links.0,
+                    BinaryIndex::Right => links.1,
+                };
+                // The children of a negated node are negated as well
+                Some(NodeHandler(match complemented {
+                    true => child.negate(),
+                    false => child,
+                }))
+            }
             super::Link::Leaf(_) => None,
         }
     }
 
-    pub fn is_leaf(&self) -> bool {
-        return matches!(self, Self(super::Link::Leaf(_)));
-    }
-
-    pub(super) fn get_parents<'a>(
-        &self,
-        diagram: &'a BinaryDecisionDiagram<T>,
-        binary_index: BinaryIndex,
-    ) -> &'a std::collections::HashSet<NodePtrMut<T>> {
-        match binary_index {
-            BinaryIndex::Left => match self.0 {
-                super::Link::Node(node) => &unsafe { &mut *node }.parents.0,
-                super::Link::Leaf(value) => match value {
-                    true => &diagram.leaf_parents.1 .0,
-                    false => &diagram.leaf_parents.0 .0,
-                },
-            },
-            BinaryIndex::Right => match self.0 {
-                super::Link::Node(node) => &unsafe { &mut *node }.parents.1,
-                super::Link::Leaf(value) => match value {
-                    true => &diagram.leaf_parents.1 .1,
-                    false => &diagram.leaf_parents.0 .1,
-                },
-            },
-        }
+    /// The negation of the node, which shares all of its nodes.
+    pub fn negate(&self) -> NodeHandler {
+        NodeHandler(self.0.negate())
     }
 
-    pub fn get_element(&self) -> Element<&T> {
-        match &self.0 {
-            super::Link::Node(node) => Element::Variable(unsafe { &(*(*node)).variable }),
-            super::Link::Leaf(value) => Element::Binary(*value),
-        }
+    /// Whether the handler is a complement edge, i.e. the negation of a stored node.
+    /// The leaf `false` is the complement of the leaf `true`.
+    pub fn is_complemented(&self) -> bool {
+        self.0.is_complemented()
     }
-}
 
-pub struct FormulaRoot<T>(NodeHandler<usize>, HashMap<usize, T>);
-
-impl<T> FormulaRoot<T> {
-    pub fn new(
-        node_handler: NodeHandler<usize>,
-        inverse_table: HashMap<usize, T>,
-    ) -> FormulaRoot<T> {
-        FormulaRoot(node_handler, inverse_table)
-    }
-}
-
-// For Display
-// returns (index, flag)
-// `flag` is true iff the index is generated by this function call
-impl<T> FormulaRoot<T>
-where
-    T: Display,
-{
-    fn get_index(
-        node_handler: &NodeHandler<usize>,
-        inverse_table: &HashMap<usize, T>,
-        f: &mut std::fmt::Formatter<'_>,
-        index: &mut u32,
-        visit_record: &mut HashMap<NodePtrMut<usize>, u32>,
-    ) -> (u32, bool) {
-        match node_handler.0 {
-            super::Link::Node(node) => {
-                if visit_record.contains_key(&node) {
-                    (visit_record[&node], false)
-                } else {
-                    let var_value =
-                        unwrap!(node_handler.get_element(), Element::Variable(var), var);
-                    let var = inverse_table.get(var_value).unwrap().to_string();
-                    writeln!(f, "{index} [label=\"{var}\"]").unwrap();
-                    let node_index = *index;
-                    visit_record.insert(node, node_index);
-                    *index += 1;
-                    (node_index, true)
-                }
-            }
-            super::Link::Leaf(value) => (value as u32, false),
-        }
-    }
-
-    pub fn generic_fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        writeln!(f, "digraph{{")?;
-        match self.0.get_element() {
-            Element::Variable(_) => {
-                writeln!(f, r#"0 [label="false"]"#)?;
-                writeln!(f, r#"1 [label="true"]"#)?;
-                let mut index = 2;
-                Self::fmt_reclusive(&self.0, &self.1, f, &mut index, &mut HashMap::new())?;
-            }
-            Element::Binary(value) => match value {
-                true => writeln!(f, r#"1 [label="true"]"#)?,
-                false => writeln!(f, r#"0 [label="false"]"#)?,
-            },
-        }
-
-        writeln!(f, "}}")?;
-        Ok(())
+    pub fn is_leaf(&self) -> bool {
+        matches!(self, Self(super::Link::Leaf(_)))
     }
 
-    fn fmt_reclusive(
-        node_handler: &NodeHandler<usize>,
-        inverse_table: &HashMap<usize, T>,
-        f: &mut std::fmt::Formatter<'_>,
-        index: &mut u32,
-        visit_record: &mut HashMap<NodePtrMut<usize>, u32>,
-    ) -> std::fmt::Result {
-        match node_handler.get_element() {
-            Element::Variable(_) => {
-                let (parent_index, _) =
-                    Self::get_index(node_handler, inverse_table, f, index, visit_record);
-                let ((left_index, left_recursive_flag), (right_index, right_recursive_flag)) = (
-                    Self::get_index(
-                        &node_handler.get_child(BinaryIndex::Left).unwrap(),
-                        inverse_table,
-                        f,
-                        index,
-                        visit_record,
-                    ),
-                    Self::get_index(
-                        &node_handler.get_child(BinaryIndex::Right).unwrap(),
-                        inverse_table,
-                        f,
-                        index,
-                        visit_record,
-                    ),
-                );
-                writeln!(f, "{parent_index} -> {left_index} [label=\"0\"]")?;
-                writeln!(f, "{parent_index} -> {right_index} [label=\"1\"]")?;
-                if left_recursive_flag {
-                    Self::fmt_reclusive(
-                        &node_handler.get_child(BinaryIndex::Left).unwrap(),
-                        inverse_table,
-                        f,
-                        index,
-                        visit_record,
-                    )?;
-                }
-                if right_recursive_flag {
-                    Self::fmt_reclusive(
-                        &node_handler.get_child(BinaryIndex::Right).unwrap(),
-                        inverse_table,
-                        f,
-                        index,
-                        visit_record,
-                    )?;
-                }
-                Ok(())
-            }
-            Element::Binary(_) => Ok(()),
+    pub fn get_element<'a, T>(&self, diagram: &'a BinaryDecisionDiagram<T>) -> Element<&'a T>
+    where
+        T: Clone,
+    {
+        match self.0 {
+            super::Link::Node(node, _) => Element::Variable(&diagram.node(node).variable),
+            super::Link::Leaf(value) => Element::Binary(value),
         }
     }
 }
-
-impl<T> Display for FormulaRoot<T>
-where
-    T: Display,
-{
-    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        self.generic_fmt(f)
-    }
-}
@@ -0,0 +1,186 @@
+use std::{cmp::Reverse, hash::Hash};
+
+use super::{BinaryDecisionDiagram, BinaryIndex, Element, NodeHandler};
+
+impl<T> BinaryDecisionDiagram<T>
+where
+    T: Clone + Eq + Ord + Hash,
+{
+    /// `f` with `variables` existentially quantified, i.e. the formula that holds iff `f`
+    /// holds for some values of `variables`.
+    pub fn exists(&mut self, f: NodeHandler, variables: &[T]) -> NodeHandler {
+        let cube = self.cube(variables);
+        self.exists_cube(f, cube)
+    }
+
+    /// `f` with `variables` universally quantified, i.e. the formula that holds iff `f`
+    /// holds for all values of `variables`.
+    pub fn forall(&mut self, f: NodeHandler, variables: &[T]) -> NodeHandler {
+        self.exists(f.negate(), variables).negate()
+    }
+
+    /// `exists variables. f & g`, computed without building `f & g`, which is often much
+    /// larger than the result. This relational product is the core of image computations.
+    pub fn and_exists(&mut self, f: NodeHandler, g: NodeHandler, variables: &[T]) -> NodeHandler {
+        let cube = self.cube(variables);
+        self.and_exists_cube(f, g, cube)
+    }
+
+    // The conjunction of those of `variables` in the diagram, which stands for the set of
+    // them in the computed tables
+    pub(super) fn cube(&mut self, variables: &[T]) -> NodeHandler {
+        let mut variables: Vec<&T> = variables
+            .iter()
+            .filter(|variable| self.levels.contains_key(variable))
+            .collect();
+        variables.sort_by_key(|variable| Reverse(self.levels[*variable]));
+        variables.dedup();
+        let (false_leaf, true_leaf) = Self::get_leaves();
+        variables.into_iter().fold(true_leaf, |cube, variable| {
+            self.add_node_if_necessary(variable.clone(), (false_leaf, cube))
+        })
+    }
+
+    // The rest of `cube` once the variables above `level` are dropped
+    pub(super) fn cube_below(&self, cube: NodeHandler, level: usize) -> NodeHandler {
+        let mut cube = cube;
+        while self.level_of(cube) < level {
+            cube = cube.get_child(self, BinaryIndex::Right).unwrap();
+        }
+        cube
+    }
+
+    fn exists_cube(&mut self, f: NodeHandler, cube: NodeHandler) -> NodeHandler {
+        let (_, true_leaf) = Self::get_leaves();
+        let cube = self.cube_below(cube, self.level_of(f));
+        if f.is_leaf() || cube == true_leaf {
+            return f;
+        }
+        if let Some(result) = self.exists_table.lookup(&(f, cube)) {
+            return result;
+        }
+
+        let variable = match f.get_element(self) {
+            Element::Variable(variable) => variable.clone(),
+            Element::Binary(_) => unreachable!(),
+        };
+        let (f0, f1) = self.cofactors(f, &variable);
+        let result = if cube.get_element(self) == Element::Variable(&variable) {
+            let cube = cube.get_child(self, BinaryIndex::Right).unwrap();
+            match self.exists_cube(f0, cube) {
+                // No need to look at the other branch
+                low if low == true_leaf => true_leaf,
+                low => {
+                    let high = self.exists_cube(f1, cube);
+                    self.ite(low, true_leaf, high)
+                }
+            }
+        } else {
+            let children = (self.exists_cube(f0, cube), self.exists_cube(f1, cube));
+            self.add_node_if_necessary(variable, children)
+        };
+        self.exists_table.insert((f, cube), result);
+        result
+    }
+
+    fn and_exists_cube(
+        &mut self,
+        f: NodeHandler,
+        g: NodeHandler,
+        cube: NodeHandler,
+    ) -> NodeHandler {
+        let (false_leaf, true_leaf) = Self::get_leaves();
+        // Basic case
+        if f == false_leaf || g == false_leaf || f == g.negate() {
+            return false_leaf;
+        }
+        if cube == true_leaf {
+            return self.ite(f, g, false_leaf);
+        }
+        if f == true_leaf || f == g {
+            return self.exists_cube(g, cube);
+        }
+        if g == true_leaf {
+            return self.exists_cube(f, cube);
+        }
+
+        // The conjunction commutes
+        let (f, g) = match g < f {
+            true => (g, f),
+            false => (f, g),
+        };
+        let level = usize::min(self.level_of(f), self.level_of(g));
+        let cube = self.cube_below(cube, level);
+        if cube == true_leaf {
+            return self.ite(f, g, false_leaf);
+        }
+        if let Some(result) = self.and_exists_table.lookup(&(f, g, cube)) {
+            return result;
+        }
+
+        let variable = match self.level_of(f) == level {
+            true => f.get_element(self),
+            false => g.get_element(self),
+        };
+        let variable = match variable {
+            Element::Variable(variable) => variable.clone(),
+            Element::Binary(_) => unreachable!(),
+        };
+        let ((f0, f1), (g0, g1)) = (self.cofactors(f, &variable), self.cofactors(g, &variable));
+        let result = if cube.get_element(self) == Element::Variable(&variable) {
+            let cube = cube.get_child(self, BinaryIndex::Right).unwrap();
+            match self.and_exists_cube(f0, g0, cube) {
+                // No need to look at the other branch
+                low if low == true_leaf => true_leaf,
+                low => {
+                    let high = self.and_exists_cube(f1, g1, cube);
+                    self.ite(low, true_leaf, high)
+                }
+            }
+        } else {
+            let children = (
+                self.and_exists_cube(f0, g0, cube),
+                self.and_exists_cube(f1, g1, cube),
+            );
+            self.add_node_if_necessary(variable, children)
+        };
+        self.and_exists_table.insert((f, g, cube), result);
+        result
+    }
+}
+
+#[test]
+fn quantification_test() {
+    let (mut diagram, x, y, z) = super::three_variables();
+    let (false_leaf, true_leaf) = BinaryDecisionDiagram::<usize>::get_leaves();
+    // x ? y : z
+    let f = diagram.ite(x, y, z);
+    let y_or_z = diagram.ite(y, true_leaf, z);
+    let y_and_z = diagram.ite(y, z, false_leaf);
+    assert_eq!(diagram.exists(f, &[0]), y_or_z);
+    assert_eq!(diagram.forall(f, &[0]), y_and_z);
+    assert_eq!(diagram.exists(f, &[1, 0, 2]), true_leaf);
+    assert_eq!(diagram.forall(f, &[0, 1]), false_leaf);
+    assert_eq!(diagram.exists(f, &[7]), f);
+    assert!(diagram.cache_statistics().misses > 0);
+}
+
+#[test]
+fn and_exists_test() {
+    let (mut diagram, x, y, z) = super::three_variables();
+    let false_leaf = BinaryDecisionDiagram::<usize>::get_leaf(false);
+    // The relation y' = !x over (x, y'), and the set of states {x = 1} given by x
+    let relation = diagram.ite(x, y.negate(), y);
+    let image = diagram.and_exists(relation, x, &[0]);
+    assert_eq!(image, y.negate());
+
+    let f = diagram.ite(x, z, y);
+    let g = diagram.ite(y, z.negate(), x);
+    let conjunction = diagram.ite(f, g, false_leaf);
+    for variables in [&[0][..], &[1], &[0, 2], &[2, 1, 0], &[]] {
+        assert_eq!(
+            diagram.and_exists(f, g, variables),
+            diagram.exists(conjunction, variables)
+        );
+    }
+}
@@ -0,0 +1,417 @@
+use std::{cmp::Reverse, collections::HashSet, hash::Hash};
+
+use super::{BinaryDecisionDiagram, Link, NodeHandler, NodeId};
+use crate::unwrap;
+
+/// Sifting stops moving a block in one direction once the diagram grows by this factor
+/// over the best size seen
+const MAXIMUM_GROWTH: f64 = 1.2;
+
+// Reordering moves blocks of adjacent levels: a group of variables, or a variable outside
+// any group. The blocks are given by their lengths from the root down.
+impl<T> BinaryDecisionDiagram<T>
+where
+    T: Clone + Eq + Ord + Hash,
+{
+    /// Exchanges the variables at `level` and `level + 1`.
+    ///
+    /// Every node keeps its function, so the handlers in use stay valid, as long as they are
+    /// referenced: the nodes left unreferenced by the exchange are freed. Panics if either
+    /// variable belongs to a group, as reordering keeps the groups together.
+    pub fn swap_adjacent_levels(&mut self, level: usize) {
+        assert!(
+            !self.groups.contains_key(&self.order[level])
+                && !self.groups.contains_key(&self.order[level + 1]),
+            "A variable of a group only moves with the whole group"
+        );
+        let mut freed = HashSet::new();
+        self.swap(level, &mut freed);
+        self.forget_freed(&freed);
+    }
+
+    /// Keeps `variables` at adjacent levels, in this order, through reordering. They are
+    /// first moved right below the topmost of them.
+    ///
+    /// Panics if a variable appears twice or already belongs to a group. As with
+    /// `swap_adjacent_levels`, reference every handle still in use first.
+    pub fn add_group(&mut self, variables: Vec<T>) {
+        assert_eq!(
+            variables.iter().collect::<HashSet<_>>().len(),
+            variables.len(),
+            "The variables of a group must be distinct"
+        );
+        for variable in &variables {
+            assert!(
+                !self.groups.contains_key(variable),
+                "The variable already belongs to a group"
+            );
+            if !self.levels.contains_key(variable) {
+                self.add_level(variable.clone());
+            }
+        }
+        let top = match variables.iter().map(|variable| self.levels[variable]).min() {
+            Some(top) => top,
+            None => return,
+        };
+
+        // Every member is alone in its block, so the levels between the members placed so
+        // far and the next one are made of whole blocks, which it can be moved above
+        let mut freed = HashSet::new();
+        let mut lengths = self.block_lengths();
+        for (offset, variable) in variables.iter().enumerate() {
+            let mut position = self.block_position(variable, &lengths);
+            let target = self.block_position(&self.order[top + offset].clone(), &lengths);
+            while position > target {
+                position = self.move_block(position, target, &mut lengths, &mut freed);
+            }
+        }
+        self.forget_freed(&freed);
+
+        let group = self.group_members.len();
+        for variable in &variables {
+            self.groups.insert(variable.clone(), group);
+        }
+        self.group_members.push(variables);
+    }
+
+    /// Reorders the variables by Rudell's sifting: each block, from the most used one on,
+    /// is moved through every position and left where the diagram is the smallest.
+    /// Returns the number of nodes afterwards.
+    ///
+    /// Garbage is collected first, so reference every handle still in use.
+    pub fn sift(&mut self) -> usize {
+        self.collect_garbage();
+        let mut lengths = self.block_lengths();
+        // Blocks are identified by their first variable, which stays first
+        let mut blocks: Vec<(T, usize)> = vec![];
+        let mut level = 0;
+        for length in &lengths {
+            let size = self.order[level..level + length]
+                .iter()
+                .map(|variable| self.unique_table[variable].len())
+                .sum();
+            blocks.push((self.order[level].clone(), size));
+            level += length;
+        }
+        blocks.sort_by_key(|(_, size)| Reverse(*size));
+        let mut freed = HashSet::new();
+        for (variable, _) in blocks {
+            let position = self.block_position(&variable, &lengths);
+            self.sift_block(position, &mut lengths, &mut freed);
+        }
+        self.forget_freed(&freed);
+        self.node_count()
+    }
+
+    /// Reorders the variables by window permutation: every `window` adjacent blocks, from
+    /// the top down, are put in the order among all of their permutations for which the
+    /// diagram is the smallest. Passes are repeated as long as the diagram shrinks.
+    /// Returns the number of nodes afterwards.
+    ///
+    /// Panics unless `window` is from 2 to 4. Garbage is collected first, so reference every
+    /// handle still in use.
+    pub fn window_permutation(&mut self, window: usize) -> usize {
+        assert!(
+            (2..=4).contains(&window),
+            "The window spans from 2 to 4 blocks"
+        );
+        self.collect_garbage();
+        let mut lengths = self.block_lengths();
+        let window = usize::min(window, lengths.len());
+        let transpositions = adjacent_transpositions(window);
+        let mut freed = HashSet::new();
+        loop {
+            let size = self.node_count();
+            for first in 0..=lengths.len() - window {
+                self.permute_window(first, window, &transpositions, &mut lengths, &mut freed);
+            }
+            if self.node_count() >= size {
+                break;
+            }
+        }
+        self.forget_freed(&freed);
+        self.node_count()
+    }
+
+    /// Sets the node count above which `reorder_if_necessary` sifts.
+    /// `None`, the default, disables automatic reordering.
+    pub fn set_reordering_threshold(&mut self, threshold: Option<usize>) {
+        self.reordering_threshold = threshold;
+    }
+
+    /// Sifts if the diagram has grown beyond the threshold. The threshold is raised
+    /// afterwards if the diagram is still large, to avoid sifting over and over.
+    ///
+    /// Only call it when every handle still in use is referenced.
+    pub fn reorder_if_necessary(&mut self) {
+        if let Some(threshold) = self.reordering_threshold {
+            if self.node_count() > threshold {
+                let node_count = self.sift();
+                self.reordering_threshold = Some(usize::max(threshold, 2 * node_count));
+            }
+        }
+    }
+
+    // The lengths of the blocks from the root down
+    fn block_lengths(&self) -> Vec<usize> {
+        let mut lengths = vec![];
+        let mut level = 0;
+        while level < self.order.len() {
+            let length = match self.groups.get(&self.order[level]) {
+                Some(group) => self.group_members[*group].len(),
+                None => 1,
+            };
+            lengths.push(length);
+            level += length;
+        }
+        lengths
+    }
+
+    // The position of the block starting with `variable`
+    fn block_position(&self, variable: &T, lengths: &[usize]) -> usize {
+        let level = self.levels[variable];
+        let mut start = 0;
+        lengths
+            .iter()
+            .position(|length| {
+                let found = start == level;
+                start += length;
+                found
+            })
+            .unwrap()
+    }
+
+    // Moves the block at `position` to the end closer to it, then to the other end, then
+    // back to the position where the diagram was the smallest
+    fn sift_block(&mut self, position: usize, lengths: &mut [usize], freed: &mut HashSet<NodeId>) {
+        let last = lengths.len() - 1;
+        let mut position = position;
+        let (mut best_position, mut best_size) = (position, self.node_count());
+        let ends = match position > last / 2 {
+            true => [last, 0],
+            false => [0, last],
+        };
+        for end in ends {
+            while position != end {
+                position = self.move_block(position, end, lengths, freed);
+                let size = self.node_count();
+                if size < best_size {
+                    (best_position, best_size) = (position, size);
+                }
+                if size as f64 > best_size as f64 * MAXIMUM_GROWTH {
+                    break;
+                }
+            }
+        }
+        while position != best_position {
+            position = self.move_block(position, best_position, lengths, freed);
+        }
+    }
+
+    // Goes through every permutation of the `window` blocks from `first` on, with the
+    // adjacent `transpositions` of the window, then back to the one with the fewest nodes
+    fn permute_window(
+        &mut self,
+        first: usize,
+        window: usize,
+        transpositions: &[usize],
+        lengths: &mut [usize],
+        freed: &mut HashSet<NodeId>,
+    ) {
+        let mut permutation: Vec<usize> = (0..window).collect();
+        let (mut best_permutation, mut best_size) = (permutation.clone(), self.node_count());
+        for transposition in transpositions {
+            self.exchange_blocks(first + transposition, lengths, freed);
+            permutation.swap(*transposition, transposition + 1);
+            let size = self.node_count();
+            if size < best_size {
+                (best_permutation, best_size) = (permutation.clone(), size);
+            }
+        }
+        for (place, block) in best_permutation.into_iter().enumerate() {
+            let mut current = permutation
+                .iter()
+                .position(|other| *other == block)
+                .unwrap();
+            while current > place {
+                self.exchange_blocks(first + current - 1, lengths, freed);
+                permutation.swap(current - 1, current);
+                current -= 1;
+            }
+        }
+    }
+
+    // Moves the block at `position` one position towards `target`, returning its new position
+    fn move_block(
+        &mut self,
+        position: usize,
+        target: usize,
+        lengths: &mut [usize],
+        freed: &mut HashSet<NodeId>,
+    ) -> usize {
+        if position < target {
+            self.exchange_blocks(position, lengths, freed);
+            position + 1
+        } else {
+            self.exchange_blocks(position - 1, lengths, freed);
+            position - 1
+        }
+    }
+
+    // Exchanges the blocks at `upper` and `upper + 1` by moving each variable of the lower
+    // block, from its top, above the upper block
+    fn exchange_blocks(
+        &mut self,
+        upper: usize,
+        lengths: &mut [usize],
+        freed: &mut HashSet<NodeId>,
+    ) {
+        let start: usize = lengths[..upper].iter().sum();
+        let (upper_length, lower_length) = (lengths[upper], lengths[upper + 1]);
+        for offset in 0..lower_length {
+            for level in (start + offset..start + offset + upper_length).rev() {
+                self.swap(level, freed);
+            }
+        }
+        lengths.swap(upper, upper + 1);
+    }
+
+    // Every node testing the upper variable `x` with a child testing the lower variable `y`
+    // is rewritten in place into a node testing `y` with children testing `x`, so that its
+    // id keeps denoting the same function. The other nodes testing `x` stay as they are.
+    fn swap(&mut self, level: usize, freed: &mut HashSet<NodeId>) {
+        let (x, y) = (self.order[level].clone(), self.order[level + 1].clone());
+        let tests_y = |diagram: &Self, link: Link| matches!(link, Link::Node(node, _) if diagram.node(node).variable == y);
+        let rewritten: Vec<((Link, Link), NodeId)> = self.unique_table[&x]
+            .iter()
+            .filter(|(links, _)| tests_y(self, links.0) || tests_y(self, links.1))
+            .map(|(links, node)| (*links, *node))
+            .collect();
+        let subtable = self.unique_table.get_mut(&x).unwrap();
+        for (links, _) in &rewritten {
+            subtable.remove(links);
+        }
+
+        for (links, node) in rewritten {
+            let ((f00, f01), (f10, f11)) = (
+                self.cofactors(NodeHandler(links.0), &y),
+                self.cofactors(NodeHandler(links.1), &y),
+            );
+            let children = (
+                self.add_node_if_necessary(x.clone(), (f00, f10)),
+                self.add_node_if_necessary(x.clone(), (f01, f11)),
+            );
+            // The right link was regular and so are its children, hence the new right child
+            debug_assert!(!children.1.is_complemented());
+            self.increase_references(children.0 .0);
+            self.increase_references(children.1 .0);
+            let new_links = (children.0 .0, children.1 .0);
+            let stored = self.node_mut(node);
+            stored.variable = y.clone();
+            stored.links = new_links;
+            self.unique_table
+                .entry(y.clone())
+                .or_default()
+                .insert(new_links, node);
+            for link in [links.0, links.1] {
+                if self.decrease_references(link) {
+                    self.free(unwrap!(link, Link::Node(child, _), child), freed);
+                }
+            }
+        }
+
+        self.order.swap(level, level + 1);
+        self.levels.insert(x, level + 1);
+        self.levels.insert(y, level);
+    }
+}
+
+// The positions `i` such that exchanging the elements at `i` and `i + 1` in turn goes
+// through every permutation of `n` elements, by the Steinhaus–Johnson–Trotter algorithm:
+// the last element sweeps across the others, which are permuted between the sweeps
+fn adjacent_transpositions(n: usize) -> Vec<usize> {
+    if n <= 1 {
+        return vec![];
+    }
+    let mut transpositions = vec![];
+    let inner = adjacent_transpositions(n - 1);
+    for (sweep, inner_transposition) in inner.iter().map(Some).chain([None]).enumerate() {
+        match sweep % 2 {
+            // The last element goes from the right end to the left end
+            0 => {
+                transpositions.extend((0..n - 1).rev());
+                transpositions.extend(inner_transposition.map(|position| position + 1));
+            }
+            _ => {
+                transpositions.extend(0..n - 1);
+                transpositions.extend(inner_transposition.copied());
+            }
+        }
+    }
+    transpositions
+}
+
+#[test]
+fn swap_test() {
+    // The truth table of `node` over the variables 0..3
+    fn truth_table(diagram: &BinaryDecisionDiagram<usize>, node: NodeHandler) -> Vec<bool> {
+        (0..8)
+            .map(|assignment: usize| {
+                let mut node = node;
+                while let super::Element::Variable(variable) = node.get_element(diagram) {
+                    let index = match assignment >> variable & 1 {
+                        0 => super::BinaryIndex::Left,
+                        _ => super::BinaryIndex::Right,
+                    };
+                    node = node.get_child(diagram, index).unwrap();
+                }
+                node == BinaryDecisionDiagram::<usize>::get_leaf(true)
+            })
+            .collect()
+    }
+
+    let mut diagram = BinaryDecisionDiagram::default();
+    let (a, b, c) = (
+        diagram.add_variable(0),
+        diagram.add_variable(1),
+        diagram.add_variable(2),
+    );
+    let a_xor_b = diagram.ite(a, b.negate(), b);
+    let f = diagram.ite(a_xor_b, c, b.negate());
+    let roots = [a, b, c, a_xor_b, f];
+    roots.iter().for_each(|root| diagram.reference(root));
+    let truth_tables: Vec<_> = roots
+        .iter()
+        .map(|root| truth_table(&diagram, *root))
+        .collect();
+
+    diagram.swap_adjacent_levels(0);
+    assert_eq!(diagram.variable_order(), &[1, 0, 2]);
+    diagram.swap_adjacent_levels(1);
+    assert_eq!(diagram.variable_order(), &[1, 2, 0]);
+    for (root, truth_table_before) in roots.iter().zip(truth_tables) {
+        assert_eq!(truth_table(&diagram, *root), truth_table_before);
+    }
+    assert_eq!(diagram.collect_garbage(), 0);
+}
+
+#[test]
+fn window_permutation_test() {
+    assert_eq!(adjacent_transpositions(3), vec![1, 0, 1, 0, 1]);
+    for n in 2..=4 {
+        let mut permutation: Vec<usize> = (0..n).collect();
+        let mut permutations = HashSet::from([permutation.clone()]);
+        for transposition in adjacent_transpositions(n) {
+            permutation.swap(transposition, transposition + 1);
+            permutations.insert(permutation.clone());
+        }
+        assert_eq!(permutations.len(), (1..=n).product());
+    }
+}
+
+#[test]
+#[should_panic(expected = "distinct")]
+fn duplicate_group_member_test() {
+    let mut diagram = BinaryDecisionDiagram::default();
+    diagram.add_group(vec![0, 1, 0]);
+}
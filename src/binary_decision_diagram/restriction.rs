@@ -0,0 +1,81 @@
+use std::{collections::HashMap, hash::Hash};
+
+use super::{BinaryDecisionDiagram, BinaryIndex, Element, NodeHandler};
+
+impl<T> BinaryDecisionDiagram<T>
+where
+    T: Clone + Eq + Ord + Hash,
+{
+    /// `f` with the variables of `assignment` set to their values, i.e. the cofactor of `f`
+    /// with respect to the partial assignment.
+    pub fn restrict(&mut self, f: NodeHandler, assignment: &HashMap<T, bool>) -> NodeHandler {
+        match self.deepest_level(assignment.keys()) {
+            Some(deepest) => self.restrict_above(f, assignment, deepest, &mut HashMap::new()),
+            None => f,
+        }
+    }
+
+    fn restrict_above(
+        &mut self,
+        f: NodeHandler,
+        assignment: &HashMap<T, bool>,
+        deepest: usize,
+        memo: &mut HashMap<NodeHandler, NodeHandler>,
+    ) -> NodeHandler {
+        if self.level_of(f) > deepest {
+            return f;
+        }
+        if let Some(result) = memo.get(&f) {
+            return *result;
+        }
+        let variable = match f.get_element(self) {
+            Element::Variable(variable) => variable.clone(),
+            Element::Binary(_) => unreachable!(),
+        };
+        let result = match assignment.get(&variable) {
+            Some(value) => {
+                let index = match value {
+                    false => BinaryIndex::Left,
+                    true => BinaryIndex::Right,
+                };
+                let child = f.get_child(self, index).unwrap();
+                self.restrict_above(child, assignment, deepest, memo)
+            }
+            None => {
+                let (low, high) = (
+                    f.get_child(self, BinaryIndex::Left).unwrap(),
+                    f.get_child(self, BinaryIndex::Right).unwrap(),
+                );
+                let children = (
+                    self.restrict_above(low, assignment, deepest, memo),
+                    self.restrict_above(high, assignment, deepest, memo),
+                );
+                self.add_node_if_necessary(variable, children)
+            }
+        };
+        memo.insert(f, result);
+        result
+    }
+}
+
+#[test]
+fn restrict_test() {
+    let (mut diagram, x, y, z) = super::three_variables();
+    // x ? !y : z
+    let f = diagram.ite(x, y.negate(), z);
+    assert_eq!(diagram.restrict(f, &HashMap::from([(0, true)])), y.negate());
+    assert_eq!(diagram.restrict(f, &HashMap::from([(0, false)])), z);
+    let (false_leaf, true_leaf) = BinaryDecisionDiagram::<usize>::get_leaves();
+    assert_eq!(
+        diagram.restrict(f.negate(), &HashMap::from([(0, true), (1, false)])),
+        false_leaf
+    );
+    let y_or_z = diagram.ite(y, true_leaf, z);
+    let not_y_or_z = diagram.ite(y.negate(), true_leaf, z);
+    let g = diagram.ite(x, y_or_z, not_y_or_z);
+    assert_eq!(
+        diagram.restrict(g, &HashMap::from([(2, false)])),
+        diagram.ite(x, y, y.negate())
+    );
+    assert_eq!(diagram.restrict(g, &HashMap::from([(5, false)])), g);
+}
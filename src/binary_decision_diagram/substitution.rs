@@ -0,0 +1,188 @@
+use std::{
+    collections::{HashMap, HashSet},
+    hash::Hash,
+};
+
+use super::{BinaryDecisionDiagram, BinaryIndex, Element, NodeHandler};
+
+impl<T> BinaryDecisionDiagram<T>
+where
+    T: Clone + Eq + Ord + Hash,
+{
+    /// `f` with the function `g` in place of `variable`.
+    pub fn compose(&mut self, f: NodeHandler, variable: &T, g: NodeHandler) -> NodeHandler {
+        self.vector_compose(f, &HashMap::from([(variable.clone(), g)]))
+    }
+
+    /// `f` with each variable of `substitution` replaced by its function, all at once: the
+    /// functions are not affected by the replacement of the other variables.
+    pub fn vector_compose(
+        &mut self,
+        f: NodeHandler,
+        substitution: &HashMap<T, NodeHandler>,
+    ) -> NodeHandler {
+        match self.deepest_level(substitution.keys()) {
+            Some(deepest) => self.compose_above(f, substitution, deepest, &mut HashMap::new()),
+            None => f,
+        }
+    }
+
+    /// `f` with each variable of `mapping` replaced by the variable it maps to, all at once.
+    /// Where the mapping keeps the order of the variables `f` depends on, the nodes are
+    /// relabeled as they are, and the result is rebuilt by ITE otherwise.
+    pub fn rename(&mut self, f: NodeHandler, mapping: &HashMap<T, T>) -> NodeHandler {
+        let support = self.support(f);
+        let images: Vec<T> = support
+            .iter()
+            .map(|variable| mapping.get(variable).unwrap_or(variable).clone())
+            .collect();
+        let images: Vec<NodeHandler> = images
+            .into_iter()
+            .map(|image| self.add_variable(image))
+            .collect();
+        let preserves_order = images
+            .windows(2)
+            .all(|pair| self.level_of(pair[0]) < self.level_of(pair[1]));
+        let substitution = support.into_iter().zip(images).collect();
+        match preserves_order {
+            true => self.relabel(f, &substitution, &mut HashMap::new()),
+            false => self.vector_compose(f, &substitution),
+        }
+    }
+
+    /// `f` with its variables permuted by `permutation`, which must map its keys one to one
+    /// onto themselves, as in swapping the current and next state variables.
+    pub fn permute(&mut self, f: NodeHandler, permutation: &HashMap<T, T>) -> NodeHandler {
+        let images: HashSet<&T> = permutation.values().collect();
+        assert!(
+            images.len() == permutation.len()
+                && images.iter().all(|image| permutation.contains_key(image)),
+            "The mapping is not a permutation"
+        );
+        self.rename(f, permutation)
+    }
+
+    // Each node of `f` testing a variable of `substitution` tests its image instead, which
+    // must come in the same order
+    fn relabel(
+        &mut self,
+        f: NodeHandler,
+        substitution: &HashMap<T, NodeHandler>,
+        memo: &mut HashMap<NodeHandler, NodeHandler>,
+    ) -> NodeHandler {
+        if f.is_leaf() {
+            return f;
+        }
+        if f.is_complemented() {
+            return self.relabel(f.negate(), substitution, memo).negate();
+        }
+        if let Some(result) = memo.get(&f) {
+            return *result;
+        }
+        let image = match f.get_element(self) {
+            Element::Variable(variable) => substitution[variable].get_element(self),
+            Element::Binary(_) => unreachable!(),
+        };
+        let image = match image {
+            Element::Variable(image) => image.clone(),
+            Element::Binary(_) => unreachable!(),
+        };
+        let (low, high) = (
+            f.get_child(self, BinaryIndex::Left).unwrap(),
+            f.get_child(self, BinaryIndex::Right).unwrap(),
+        );
+        let children = (
+            self.relabel(low, substitution, memo),
+            self.relabel(high, substitution, memo),
+        );
+        let result = self.add_node_if_necessary(image, children);
+        memo.insert(f, result);
+        result
+    }
+
+    // Each node is rebuilt bottom up as `ite(replacement of its variable, high, low)`, which
+    // keeps the result reduced and ordered wherever the replacements go
+    fn compose_above(
+        &mut self,
+        f: NodeHandler,
+        substitution: &HashMap<T, NodeHandler>,
+        deepest: usize,
+        memo: &mut HashMap<NodeHandler, NodeHandler>,
+    ) -> NodeHandler {
+        if self.level_of(f) > deepest {
+            return f;
+        }
+        // The result for the negation is the negation of the result
+        if f.is_complemented() {
+            return self
+                .compose_above(f.negate(), substitution, deepest, memo)
+                .negate();
+        }
+        if let Some(result) = memo.get(&f) {
+            return *result;
+        }
+        let variable = match f.get_element(self) {
+            Element::Variable(variable) => variable.clone(),
+            Element::Binary(_) => unreachable!(),
+        };
+        let (low, high) = (
+            f.get_child(self, BinaryIndex::Left).unwrap(),
+            f.get_child(self, BinaryIndex::Right).unwrap(),
+        );
+        let (low, high) = (
+            self.compose_above(low, substitution, deepest, memo),
+            self.compose_above(high, substitution, deepest, memo),
+        );
+        let replacement = match substitution.get(&variable) {
+            Some(replacement) => *replacement,
+            None => self.add_variable(variable),
+        };
+        let result = self.ite(replacement, high, low);
+        memo.insert(f, result);
+        result
+    }
+}
+
+#[test]
+fn compose_test() {
+    let (mut diagram, x, y, z) = super::three_variables();
+    let (false_leaf, true_leaf) = BinaryDecisionDiagram::<usize>::get_leaves();
+    let x_and_y = diagram.ite(x, y, false_leaf);
+    let y_or_z = diagram.ite(y, true_leaf, z);
+    // x & y with x := y | z gives y
+    assert_eq!(diagram.compose(x_and_y, &0, y_or_z), y);
+    // x & y with y := !x gives false
+    assert_eq!(diagram.compose(x_and_y, &1, x.negate()), false_leaf);
+    // Swapping x and y at once leaves x & y unchanged, and turns x & !y into y & !x
+    let swap = HashMap::from([(0, y), (1, x)]);
+    assert_eq!(diagram.vector_compose(x_and_y, &swap), x_and_y);
+    let x_and_not_y = diagram.ite(x, y.negate(), false_leaf);
+    let y_and_not_x = diagram.ite(y, x.negate(), false_leaf);
+    assert_eq!(diagram.vector_compose(x_and_not_y, &swap), y_and_not_x);
+}
+
+#[test]
+fn rename_test() {
+    let (mut diagram, x, y, z) = super::three_variables();
+    let false_leaf = BinaryDecisionDiagram::<usize>::get_leaf(false);
+    // x & !y, renamed with the order kept, then against it
+    let f = diagram.ite(x, y.negate(), false_leaf);
+    let y_and_not_z = diagram.ite(y, z.negate(), false_leaf);
+    assert_eq!(
+        diagram.rename(f, &HashMap::from([(0, 1), (1, 2)])),
+        y_and_not_z
+    );
+    let z_and_not_x = diagram.ite(z, x.negate(), false_leaf);
+    assert_eq!(
+        diagram.rename(f, &HashMap::from([(0, 2), (1, 0)])),
+        z_and_not_x
+    );
+    let y_and_not_x = diagram.ite(y, x.negate(), false_leaf);
+    assert_eq!(
+        diagram.permute(f, &HashMap::from([(0, 1), (1, 0)])),
+        y_and_not_x
+    );
+    // A variable new to the diagram
+    let w = diagram.rename(x, &HashMap::from([(0, 7)]));
+    assert_eq!(diagram.support(w), vec![7]);
+}
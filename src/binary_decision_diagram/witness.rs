@@ -0,0 +1,100 @@
+use std::hash::Hash;
+
+use super::{BinaryDecisionDiagram, BinaryIndex, Element, NodeHandler};
+
+impl<T> BinaryDecisionDiagram<T>
+where
+    T: Clone + Eq + Ord + Hash,
+{
+    /// A satisfying cube of `f`, i.e. the values of the variables along a path to the true
+    /// leaf in the variable order, the other variables being free. `None` if `f` is false.
+    /// Takes a single walk down the diagram, as every node but the false leaf leads to the
+    /// true leaf.
+    pub fn any_sat(&self, f: NodeHandler) -> Option<Vec<(T, bool)>> {
+        let false_leaf = Self::get_leaf(false);
+        if f == false_leaf {
+            return None;
+        }
+        let mut cube = vec![];
+        let mut node = f;
+        while let Element::Variable(variable) = node.get_element(self) {
+            let low = node.get_child(self, BinaryIndex::Left).unwrap();
+            let value = low == false_leaf;
+            node = match value {
+                false => low,
+                true => node.get_child(self, BinaryIndex::Right).unwrap(),
+            };
+            cube.push((variable.clone(), value));
+        }
+        Some(cube)
+    }
+
+    /// The smallest satisfying assignment of `f` to the variables it depends on, comparing
+    /// their values in the variable order with false before true. `None` if `f` is false.
+    pub fn min_sat(&self, f: NodeHandler) -> Option<Vec<(T, bool)>> {
+        self.extreme_sat(f, false)
+    }
+
+    /// The largest satisfying assignment of `f` to the variables it depends on, comparing
+    /// their values in the variable order with false before true. `None` if `f` is false.
+    pub fn max_sat(&self, f: NodeHandler) -> Option<Vec<(T, bool)>> {
+        self.extreme_sat(f, true)
+    }
+
+    // Takes the `preferred` value for each variable in turn whenever `f` remains satisfiable
+    fn extreme_sat(&self, f: NodeHandler, preferred: bool) -> Option<Vec<(T, bool)>> {
+        let false_leaf = Self::get_leaf(false);
+        if f == false_leaf {
+            return None;
+        }
+        let child = |node: NodeHandler, value| {
+            let index = match value {
+                false => BinaryIndex::Left,
+                true => BinaryIndex::Right,
+            };
+            node.get_child(self, index).unwrap()
+        };
+        let mut node = f;
+        let assignment = self
+            .support(f)
+            .into_iter()
+            .map(|variable| {
+                // A variable skipped on the way takes the preferred value
+                if node.get_element(self) != Element::Variable(&variable) {
+                    return (variable, preferred);
+                }
+                let value = match child(node, preferred) == false_leaf {
+                    false => preferred,
+                    true => !preferred,
+                };
+                node = child(node, value);
+                (variable, value)
+            })
+            .collect();
+        Some(assignment)
+    }
+}
+
+#[test]
+fn witness_test() {
+    let (mut diagram, x, y, z) = super::three_variables();
+    let (false_leaf, true_leaf) = BinaryDecisionDiagram::<usize>::get_leaves();
+    // (x | z) & !(y & z)
+    let x_or_z = diagram.ite(x, true_leaf, z);
+    let y_and_z = diagram.ite(y, z, false_leaf);
+    let f = diagram.ite(x_or_z, y_and_z.negate(), false_leaf);
+    assert_eq!(
+        diagram.min_sat(f),
+        Some(vec![(0, false), (1, false), (2, true)])
+    );
+    assert_eq!(
+        diagram.max_sat(f),
+        Some(vec![(0, true), (1, true), (2, false)])
+    );
+    let cube = diagram.any_sat(f).unwrap();
+    let assignment = cube.into_iter().collect();
+    assert_eq!(diagram.restrict(f, &assignment), true_leaf);
+    assert_eq!(diagram.any_sat(false_leaf), None);
+    assert_eq!(diagram.min_sat(false_leaf), None);
+    assert_eq!(diagram.max_sat(true_leaf), Some(vec![]));
+}
@@ -1,5 +1,5 @@
 // auto-generated: "lalrpop 0.19.8"
-// sha3: dd30da1abca12235d37625aab0a55cec1832708e8b7df75288a695a26672f32c
+// sha3: 2647e0898a1af7b0619d3f165d0abb42cb495e3b2b2d8236926bcb140d5a564d
 use crate::formula_parser::*;
 #[allow(unused_extern_crates)]
 extern crate lalrpop_util as __lalrpop_util;
@@ -9,7 +9,7 @@ extern crate core;
 extern crate alloc;
 
 #[cfg_attr(rustfmt, rustfmt_skip)]
-mod __parse__Formula {
+mod __parse__Input {
     #![allow(non_snake_case, non_camel_case_types, unused_mut, unused_variables, unused_imports, unused_parens, clippy::all)]
 
     use crate::formula_parser::*;
@@ -24,63 +24,142 @@ mod __parse__Formula {
     pub(crate) enum __Symbol<'input>
      {
         Variant0(&'input str),
-        Variant1(ParserNode<String>),
-        Variant2(String),
+        Variant1(String),
+        Variant2(alloc::vec::Vec<String>),
+        Variant3(ParserNode<String>),
+        Variant4(Quantifier),
+        Variant5((Vec<String>, ParserNode<String>)),
+        Variant6(Vec<String>),
+        Variant7(core::option::Option<Vec<String>>),
     }
     const __ACTION: &[i8] = &[
         // State 0
-        2, 0, 3, 0, 0, 0, 16, 17, 0, 18,
+        4, 0, 5, 0, 0, 0, 0, 0, 0, 0, 33, 34, 35, 36, 37, 0, 38,
         // State 1
-        2, 0, 3, 0, 0, 0, 16, 17, 0, 18,
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 39, 40, 41, 0, 38,
         // State 2
-        2, 0, 3, 0, 0, 0, 16, 17, 0, 18,
+        4, 0, 5, 0, 0, 0, 0, 0, 0, 0, 33, 34, 35, 36, 41, 0, 38,
         // State 3
-        2, 0, 3, 0, 0, 0, 16, 17, 0, 18,
+        4, 0, 5, 0, 0, 0, 0, 0, 0, 0, 33, 34, 35, 36, 41, 0, 38,
         // State 4
-        2, 0, 3, 0, 0, 0, 16, 17, 0, 18,
+        4, 0, 5, 0, 0, 0, 0, 0, 0, 0, 33, 34, 35, 36, 41, 0, 38,
         // State 5
-        2, 0, 3, 0, 0, 0, 16, 17, 0, 18,
+        4, 0, 5, 0, 0, 0, 0, 0, 0, 0, 33, 34, 35, 36, 41, 0, 38,
         // State 6
-        2, 0, 3, 0, 0, 0, 16, 17, 0, 18,
+        0, 0, 0, 0, 12, 0, 13, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
         // State 7
-        0, 4, 0, -13, -13, -13, 0, 0, -13, 0,
+        4, 0, 5, 0, 0, 0, 0, 0, 0, 0, 33, 34, 35, 36, 41, 0, 38,
         // State 8
-        0, -15, 0, -15, -15, -15, 0, 0, -15, 0,
+        4, 0, 5, 0, 0, 0, 0, 0, 0, 0, 33, 34, 35, 36, 41, 0, 38,
         // State 9
-        0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        4, 0, 5, 0, 0, 0, 0, 0, 0, 0, 33, 34, 35, 36, 41, 0, 38,
         // State 10
-        0, 0, 0, -5, 5, 6, 0, 0, 0, 0,
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 39, 40, 41, 0, 38,
         // State 11
-        0, -14, 0, -14, -14, -14, 0, 0, -14, 0,
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 39, 40, 41, 0, 38,
         // State 12
-        0, -2, 0, -2, -2, -2, 0, 0, -2, 0,
+        4, 0, 5, 0, 0, 0, 0, 0, 0, 0, 33, 34, 35, 36, 41, 0, 38,
         // State 13
-        0, 0, 0, -8, -8, -8, 0, 0, 7, 0,
+        -41, 0, -41, 0, 0, 0, 0, 0, 17, 0, -41, -41, -41, -41, -41, 0, -41,
         // State 14
-        0, -11, 0, -11, -11, -11, 0, 0, -11, 0,
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 39, 40, 41, 0, 38,
         // State 15
-        0, -4, 0, -4, -4, -4, 0, 0, -4, 0,
+        4, 0, 5, 0, 0, 0, 0, 0, 0, 0, 33, 34, 35, 36, 41, 0, 38,
         // State 16
-        0, -3, 0, -3, -3, -3, 0, 0, -3, 0,
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 39, 40, 41, 0, 38,
         // State 17
-        0, -9, 0, -9, -9, -9, 0, 0, -9, 0,
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 39, 40, 41, 0, 38,
         // State 18
-        0, -10, 0, -10, -10, -10, 0, 0, -10, 0,
+        0, 6, 0, -40, 0, -40, 0, 0, 0, -40, 0, 0, 0, 0, 0, -40, 0,
         // State 19
-        0, 0, 0, 25, 0, 0, 0, 0, 0, 0,
+        0, -48, 0, -48, 0, -48, 0, 0, 0, -48, 0, 0, 0, 0, 0, -48, 0,
         // State 20
-        0, -1, 0, -1, -1, -1, 0, 0, -1, 0,
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
         // State 21
-        0, 0, 0, -6, -6, -6, 0, 0, 7, 0,
+        0, 0, 0, -18, 0, 8, 0, 0, 0, 9, 0, 0, 0, 0, 0, 0, 0,
         // State 22
-        0, 0, 0, -7, -7, -7, 0, 0, 7, 0,
+        0, -47, 0, -47, 0, -47, 0, 0, 0, -47, 0, 0, 0, 0, 0, -47, 0,
         // State 23
-        0, 4, 0, -12, -12, -12, 0, 0, -12, 0,
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
         // State 24
-        0, -16, 0, -16, -16, -16, 0, 0, -16, 0,
+        0, -12, 0, -12, 0, -12, 0, 0, 0, -12, 0, 0, 0, 0, 0, -12, 0,
+        // State 25
+        0, 0, 0, -38, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 26
+        0, 0, 0, -17, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 27
+        0, 0, 0, -31, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 28
+        0, 0, 0, -34, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 29
+        0, 0, 0, -21, 0, -21, 0, 0, 0, -21, 0, 0, 0, 0, 0, 10, 0,
+        // State 30
+        0, 0, 0, -36, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 31
+        0, -29, 0, -29, 0, -29, 0, 0, 0, -29, 0, 0, 0, 0, 0, -29, 0,
+        // State 32
+        0, -16, 0, -16, 0, -16, 0, 0, 0, -16, 0, 0, 0, 0, 0, -16, 0,
+        // State 33
+        0, -15, 0, -15, 0, -15, 0, 0, 0, -15, 0, 0, 0, 0, 0, -15, 0,
+        // State 34
+        0, -24, 0, -24, 0, -24, 0, 0, 0, -24, 0, 0, -13, -13, -13, -24, -13,
+        // State 35
+        0, -25, 0, -25, 0, -25, 0, 0, 0, -25, 0, 0, -14, -14, -14, -25, -14,
+        // State 36
+        0, -23, 0, 0, 0, -23, 0, 11, 0, -23, 0, 0, 0, 0, 0, -23, 0,
+        // State 37
+        -22, -22, -22, -22, -22, -22, -22, 0, -22, -22, -22, -22, -22, -22, -22, -22, -22,
+        // State 38
+        -24, 0, -24, 0, -24, 0, -24, 0, -24, 0, -24, -24, -24, -24, -24, 0, -24,
+        // State 39
+        -25, 0, -25, 0, -25, 0, -25, 0, -25, 0, -25, -25, -25, -25, -25, 0, -25,
+        // State 40
+        -23, -23, -23, -23, -23, -23, -23, 0, -23, -23, -23, -23, -23, -23, -23, -23, -23,
+        // State 41
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 42
+        0, -28, 0, -28, 0, -28, 0, 0, 0, -28, 0, 0, 0, 0, 0, -28, 0,
+        // State 43
+        0, 0, 0, -35, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 44
+        0, 0, 0, 55, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 45
+        0, -11, 0, -11, 0, -11, 0, 0, 0, -11, 0, 0, 0, 0, 0, -11, 0,
+        // State 46
+        0, 0, 0, -30, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 47
+        0, 0, 0, 0, 15, 0, 16, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 48
+        0, 0, 0, -32, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 49
+        0, 0, 0, -19, 0, -19, 0, 0, 0, -19, 0, 0, 0, 0, 0, 10, 0,
+        // State 50
+        0, 0, 0, -33, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 51
+        0, 0, 0, -20, 0, -20, 0, 0, 0, -20, 0, 0, 0, 0, 0, 10, 0,
+        // State 52
+        0, 6, 0, -39, 0, -39, 0, 0, 0, -39, 0, 0, 0, 0, 0, -39, 0,
+        // State 53
+        0, 0, 0, -37, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 54
+        0, -49, 0, -49, 0, -49, 0, 0, 0, -49, 0, 0, 0, 0, 0, -49, 0,
+        // State 55
+        0, 0, 0, 0, -4, 0, -4, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 56
+        0, 0, 0, -45, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 57
+        -42, 0, -42, 0, 0, 0, 0, 0, 18, 0, -42, -42, -42, -42, -42, 0, -42,
+        // State 58
+        0, 0, 0, 0, -5, 0, -5, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 59
+        0, 0, 0, -46, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 60
+        -9, 0, -9, 0, 0, 0, 0, 0, -9, 0, -9, -9, -9, -9, -9, 0, -9,
+        // State 61
+        -10, 0, -10, 0, 0, 0, 0, 0, -10, 0, -10, -10, -10, -10, -10, 0, -10,
     ];
     fn __action(state: i8, integer: usize) -> i8 {
-        __ACTION[(state as usize) * 10 + integer]
+        __ACTION[(state as usize) * 17 + integer]
     }
     const __EOF_ACTION: &[i8] = &[
         // State 0
@@ -98,66 +177,172 @@ mod __parse__Formula {
         // State 6
         0,
         // State 7
-        -13,
+        0,
         // State 8
-        -15,
+        0,
         // State 9
-        -17,
+        0,
         // State 10
-        -5,
+        0,
         // State 11
-        -14,
+        0,
         // State 12
-        -2,
+        0,
         // State 13
-        -8,
+        0,
         // State 14
-        -11,
+        0,
         // State 15
-        -4,
+        0,
         // State 16
-        -3,
+        0,
         // State 17
-        -9,
+        0,
         // State 18
-        -10,
+        -40,
         // State 19
-        0,
+        -48,
         // State 20
-        -1,
+        -27,
         // State 21
-        -6,
+        -18,
         // State 22
-        -7,
+        -47,
         // State 23
-        -12,
+        -50,
         // State 24
+        -12,
+        // State 25
+        -38,
+        // State 26
+        -17,
+        // State 27
+        -31,
+        // State 28
+        -34,
+        // State 29
+        -21,
+        // State 30
+        -36,
+        // State 31
+        -29,
+        // State 32
         -16,
+        // State 33
+        -15,
+        // State 34
+        -24,
+        // State 35
+        -25,
+        // State 36
+        -23,
+        // State 37
+        -22,
+        // State 38
+        0,
+        // State 39
+        0,
+        // State 40
+        -23,
+        // State 41
+        -26,
+        // State 42
+        -28,
+        // State 43
+        -35,
+        // State 44
+        0,
+        // State 45
+        -11,
+        // State 46
+        -30,
+        // State 47
+        0,
+        // State 48
+        -32,
+        // State 49
+        -19,
+        // State 50
+        -33,
+        // State 51
+        -20,
+        // State 52
+        -39,
+        // State 53
+        -37,
+        // State 54
+        -49,
+        // State 55
+        0,
+        // State 56
+        -45,
+        // State 57
+        0,
+        // State 58
+        0,
+        // State 59
+        -46,
+        // State 60
+        0,
+        // State 61
+        0,
     ];
     fn __goto(state: i8, nt: usize) -> i8 {
         match nt {
-            0 => match state {
-                6 => 23,
-                _ => 7,
+            2 => 47,
+            5 => 57,
+            6 => match state {
+                9 => 52,
+                _ => 18,
             },
-            1 => 8,
-            2 => match state {
-                2 => 19,
-                _ => 9,
+            7 => 1,
+            8 => 19,
+            9 => match state {
+                2 => 41,
+                4 => 44,
+                12 => 56,
+                15 => 59,
+                _ => 20,
             },
-            3 => 10,
-            4 => 11,
-            5 => match state {
-                1 => 18,
-                3 => 20,
-                _ => 12,
+            10 => 21,
+            11 => match state {
+                1 => 6,
+                10 => 13,
+                11 => 55,
+                14 => 58,
+                16 => 60,
+                17 => 61,
+                _ => 22,
             },
-            6 => match state {
-                4 => 21,
-                5 => 22,
-                _ => 13,
+            12 => 23,
+            13 => match state {
+                3 => 42,
+                5 => 45,
+                _ => 24,
+            },
+            14 => match state {
+                9 => 53,
+                _ => 25,
             },
-            7 => 14,
+            15 => 26,
+            16 => match state {
+                3 => 43,
+                5 => 46,
+                _ => 27,
+            },
+            17 => match state {
+                7 => 48,
+                8 => 50,
+                _ => 28,
+            },
+            18 => match state {
+                7 => 49,
+                8 => 51,
+                _ => 29,
+            },
+            19 => 2,
+            21 => 30,
+            22 => 31,
             _ => 0,
         }
     }
@@ -167,10 +352,17 @@ mod __parse__Formula {
             r###""&""###,
             r###""(""###,
             r###"")""###,
+            r###"",""###,
             r###""->""###,
+            r###"".""###,
+            r###"":""###,
+            r###""<""###,
             r###""<->""###,
             r###""F""###,
             r###""T""###,
+            r###""exists""###,
+            r###""forall""###,
+            r###""order""###,
             r###""|""###,
             r###"r#"[a-zA-Z0-9]+"#"###,
         ];
@@ -197,7 +389,7 @@ mod __parse__Formula {
         type Token = Token<'input>;
         type TokenIndex = usize;
         type Symbol = __Symbol<'input>;
-        type Success = ParserNode<String>;
+        type Success = (Vec<String>, ParserNode<String>);
         type StateIndex = i8;
         type Action = i8;
         type ReduceIndex = i8;
@@ -225,7 +417,7 @@ mod __parse__Formula {
 
         #[inline]
         fn error_action(&self, state: i8) -> i8 {
-            __action(state, 10 - 1)
+            __action(state, 17 - 1)
         }
 
         #[inline]
@@ -297,7 +489,14 @@ mod __parse__Formula {
             Token(7, _) if true => Some(6),
             Token(8, _) if true => Some(7),
             Token(9, _) if true => Some(8),
-            Token(0, _) if true => Some(9),
+            Token(10, _) if true => Some(9),
+            Token(11, _) if true => Some(10),
+            Token(12, _) if true => Some(11),
+            Token(13, _) if true => Some(12),
+            Token(14, _) if true => Some(13),
+            Token(15, _) if true => Some(14),
+            Token(16, _) if true => Some(15),
+            Token(0, _) if true => Some(16),
             _ => None,
         }
     }
@@ -310,22 +509,22 @@ mod __parse__Formula {
     ) -> __Symbol<'input>
     {
         match __token_index {
-            0 | 1 | 2 | 3 | 4 | 5 | 6 | 7 | 8 | 9 => match __token {
-                Token(1, __tok0) | Token(2, __tok0) | Token(3, __tok0) | Token(4, __tok0) | Token(5, __tok0) | Token(6, __tok0) | Token(7, __tok0) | Token(8, __tok0) | Token(9, __tok0) | Token(0, __tok0) if true => __Symbol::Variant0(__tok0),
+            0 | 1 | 2 | 3 | 4 | 5 | 6 | 7 | 8 | 9 | 10 | 11 | 12 | 13 | 14 | 15 | 16 => match __token {
+                Token(1, __tok0) | Token(2, __tok0) | Token(3, __tok0) | Token(4, __tok0) | Token(5, __tok0) | Token(6, __tok0) | Token(7, __tok0) | Token(8, __tok0) | Token(9, __tok0) | Token(10, __tok0) | Token(11, __tok0) | Token(12, __tok0) | Token(13, __tok0) | Token(14, __tok0) | Token(15, __tok0) | Token(16, __tok0) | Token(0, __tok0) if true => __Symbol::Variant0(__tok0),
                 _ => unreachable!(),
             },
             _ => unreachable!(),
         }
     }
-    pub struct FormulaParser {
+    pub struct InputParser {
         builder: __lalrpop_util::lexer::MatcherBuilder,
         _priv: (),
     }
 
-    impl FormulaParser {
-        pub fn new() -> FormulaParser {
+    impl InputParser {
+        pub fn new() -> InputParser {
             let __builder = super::__intern_token::new_builder();
-            FormulaParser {
+            InputParser {
                 builder: __builder,
                 _priv: (),
             }
@@ -337,7 +536,7 @@ mod __parse__Formula {
         >(
             &self,
             input: &'input str,
-        ) -> Result<ParserNode<String>, __lalrpop_util::ParseError<usize, Token<'input>, &'static str>>
+        ) -> Result<(Vec<String>, ParserNode<String>), __lalrpop_util::ParseError<usize, Token<'input>, &'static str>>
         {
             let mut __tokens = self.builder.matcher(input);
             __state_machine::Parser::drive(
@@ -358,7 +557,7 @@ mod __parse__Formula {
         __states: &mut alloc::vec::Vec<i8>,
         __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
         _: core::marker::PhantomData<(&'input ())>,
-    ) -> Option<Result<ParserNode<String>,__lalrpop_util::ParseError<usize, Token<'input>, &'static str>>>
+    ) -> Option<Result<(Vec<String>, ParserNode<String>),__lalrpop_util::ParseError<usize, Token<'input>, &'static str>>>
     {
         let (__pop_states, __nonterminal) = match __action {
             0 => {
@@ -410,8 +609,107 @@ mod __parse__Formula {
                 __reduce15(input, __lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
             }
             16 => {
-                // __Formula = Formula => ActionFn(0);
-                let __sym0 = __pop_Variant1(__symbols);
+                __reduce16(input, __lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
+            }
+            17 => {
+                __reduce17(input, __lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
+            }
+            18 => {
+                __reduce18(input, __lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
+            }
+            19 => {
+                __reduce19(input, __lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
+            }
+            20 => {
+                __reduce20(input, __lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
+            }
+            21 => {
+                __reduce21(input, __lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
+            }
+            22 => {
+                __reduce22(input, __lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
+            }
+            23 => {
+                __reduce23(input, __lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
+            }
+            24 => {
+                __reduce24(input, __lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
+            }
+            25 => {
+                __reduce25(input, __lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
+            }
+            26 => {
+                __reduce26(input, __lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
+            }
+            27 => {
+                __reduce27(input, __lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
+            }
+            28 => {
+                __reduce28(input, __lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
+            }
+            29 => {
+                __reduce29(input, __lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
+            }
+            30 => {
+                __reduce30(input, __lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
+            }
+            31 => {
+                __reduce31(input, __lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
+            }
+            32 => {
+                __reduce32(input, __lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
+            }
+            33 => {
+                __reduce33(input, __lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
+            }
+            34 => {
+                __reduce34(input, __lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
+            }
+            35 => {
+                __reduce35(input, __lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
+            }
+            36 => {
+                __reduce36(input, __lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
+            }
+            37 => {
+                __reduce37(input, __lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
+            }
+            38 => {
+                __reduce38(input, __lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
+            }
+            39 => {
+                __reduce39(input, __lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
+            }
+            40 => {
+                __reduce40(input, __lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
+            }
+            41 => {
+                __reduce41(input, __lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
+            }
+            42 => {
+                __reduce42(input, __lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
+            }
+            43 => {
+                __reduce43(input, __lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
+            }
+            44 => {
+                __reduce44(input, __lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
+            }
+            45 => {
+                __reduce45(input, __lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
+            }
+            46 => {
+                __reduce46(input, __lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
+            }
+            47 => {
+                __reduce47(input, __lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
+            }
+            48 => {
+                __reduce48(input, __lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
+            }
+            49 => {
+                // __Input = Input => ActionFn(0);
+                let __sym0 = __pop_Variant5(__symbols);
                 let __start = __sym0.0.clone();
                 let __end = __sym0.2.clone();
                 let __nt = super::__action0::<>(input, __sym0);
@@ -430,28 +728,83 @@ mod __parse__Formula {
     fn __symbol_type_mismatch() -> ! {
         panic!("symbol type mismatch")
     }
-    fn __pop_Variant1<
+    fn __pop_Variant5<
+      'input,
+    >(
+        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>
+    ) -> (usize, (Vec<String>, ParserNode<String>), usize)
+     {
+        match __symbols.pop() {
+            Some((__l, __Symbol::Variant5(__v), __r)) => (__l, __v, __r),
+            _ => __symbol_type_mismatch()
+        }
+    }
+    fn __pop_Variant3<
       'input,
     >(
         __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>
     ) -> (usize, ParserNode<String>, usize)
+     {
+        match __symbols.pop() {
+            Some((__l, __Symbol::Variant3(__v), __r)) => (__l, __v, __r),
+            _ => __symbol_type_mismatch()
+        }
+    }
+    fn __pop_Variant4<
+      'input,
+    >(
+        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>
+    ) -> (usize, Quantifier, usize)
+     {
+        match __symbols.pop() {
+            Some((__l, __Symbol::Variant4(__v), __r)) => (__l, __v, __r),
+            _ => __symbol_type_mismatch()
+        }
+    }
+    fn __pop_Variant1<
+      'input,
+    >(
+        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>
+    ) -> (usize, String, usize)
      {
         match __symbols.pop() {
             Some((__l, __Symbol::Variant1(__v), __r)) => (__l, __v, __r),
             _ => __symbol_type_mismatch()
         }
     }
+    fn __pop_Variant6<
+      'input,
+    >(
+        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>
+    ) -> (usize, Vec<String>, usize)
+     {
+        match __symbols.pop() {
+            Some((__l, __Symbol::Variant6(__v), __r)) => (__l, __v, __r),
+            _ => __symbol_type_mismatch()
+        }
+    }
     fn __pop_Variant2<
       'input,
     >(
         __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>
-    ) -> (usize, String, usize)
+    ) -> (usize, alloc::vec::Vec<String>, usize)
      {
         match __symbols.pop() {
             Some((__l, __Symbol::Variant2(__v), __r)) => (__l, __v, __r),
             _ => __symbol_type_mismatch()
         }
     }
+    fn __pop_Variant7<
+      'input,
+    >(
+        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>
+    ) -> (usize, core::option::Option<Vec<String>>, usize)
+     {
+        match __symbols.pop() {
+            Some((__l, __Symbol::Variant7(__v), __r)) => (__l, __v, __r),
+            _ => __symbol_type_mismatch()
+        }
+    }
     fn __pop_Variant0<
       'input,
     >(
@@ -472,16 +825,15 @@ mod __parse__Formula {
         _: core::marker::PhantomData<(&'input ())>,
     ) -> (usize, usize)
     {
-        // AndSubExpr = AndSubExpr, "&", NotSubExpr => ActionFn(7);
-        assert!(__symbols.len() >= 3);
-        let __sym2 = __pop_Variant1(__symbols);
-        let __sym1 = __pop_Variant0(__symbols);
-        let __sym0 = __pop_Variant1(__symbols);
+        // ("," <Indent>) = ",", Indent => ActionFn(37);
+        assert!(__symbols.len() >= 2);
+        let __sym1 = __pop_Variant1(__symbols);
+        let __sym0 = __pop_Variant0(__symbols);
         let __start = __sym0.0.clone();
-        let __end = __sym2.2.clone();
-        let __nt = super::__action7::<>(input, __sym0, __sym1, __sym2);
+        let __end = __sym1.2.clone();
+        let __nt = super::__action37::<>(input, __sym0, __sym1);
         __symbols.push((__start, __Symbol::Variant1(__nt), __end));
-        (3, 0)
+        (2, 0)
     }
     pub(crate) fn __reduce1<
         'input,
@@ -492,13 +844,12 @@ mod __parse__Formula {
         _: core::marker::PhantomData<(&'input ())>,
     ) -> (usize, usize)
     {
-        // AndSubExpr = NotSubExpr => ActionFn(8);
-        let __sym0 = __pop_Variant1(__symbols);
-        let __start = __sym0.0.clone();
-        let __end = __sym0.2.clone();
-        let __nt = super::__action8::<>(input, __sym0);
-        __symbols.push((__start, __Symbol::Variant1(__nt), __end));
-        (1, 0)
+        // ("," <Indent>)* =  => ActionFn(35);
+        let __start = __lookahead_start.cloned().or_else(|| __symbols.last().map(|s| s.2.clone())).unwrap_or_default();
+        let __end = __start.clone();
+        let __nt = super::__action35::<>(input, &__start, &__end);
+        __symbols.push((__start, __Symbol::Variant2(__nt), __end));
+        (0, 1)
     }
     pub(crate) fn __reduce2<
         'input,
@@ -509,12 +860,12 @@ mod __parse__Formula {
         _: core::marker::PhantomData<(&'input ())>,
     ) -> (usize, usize)
     {
-        // Constant = "T" => ActionFn(14);
-        let __sym0 = __pop_Variant0(__symbols);
+        // ("," <Indent>)* = ("," <Indent>)+ => ActionFn(36);
+        let __sym0 = __pop_Variant2(__symbols);
         let __start = __sym0.0.clone();
         let __end = __sym0.2.clone();
-        let __nt = super::__action14::<>(input, __sym0);
-        __symbols.push((__start, __Symbol::Variant1(__nt), __end));
+        let __nt = super::__action36::<>(input, __sym0);
+        __symbols.push((__start, __Symbol::Variant2(__nt), __end));
         (1, 1)
     }
     pub(crate) fn __reduce3<
@@ -526,13 +877,15 @@ mod __parse__Formula {
         _: core::marker::PhantomData<(&'input ())>,
     ) -> (usize, usize)
     {
-        // Constant = "F" => ActionFn(15);
+        // ("," <Indent>)+ = ",", Indent => ActionFn(47);
+        assert!(__symbols.len() >= 2);
+        let __sym1 = __pop_Variant1(__symbols);
         let __sym0 = __pop_Variant0(__symbols);
         let __start = __sym0.0.clone();
-        let __end = __sym0.2.clone();
-        let __nt = super::__action15::<>(input, __sym0);
-        __symbols.push((__start, __Symbol::Variant1(__nt), __end));
-        (1, 1)
+        let __end = __sym1.2.clone();
+        let __nt = super::__action47::<>(input, __sym0, __sym1);
+        __symbols.push((__start, __Symbol::Variant2(__nt), __end));
+        (2, 2)
     }
     pub(crate) fn __reduce4<
         'input,
@@ -543,13 +896,16 @@ mod __parse__Formula {
         _: core::marker::PhantomData<(&'input ())>,
     ) -> (usize, usize)
     {
-        // Formula = ImplAndEquivSubExpr => ActionFn(1);
-        let __sym0 = __pop_Variant1(__symbols);
+        // ("," <Indent>)+ = ("," <Indent>)+, ",", Indent => ActionFn(48);
+        assert!(__symbols.len() >= 3);
+        let __sym2 = __pop_Variant1(__symbols);
+        let __sym1 = __pop_Variant0(__symbols);
+        let __sym0 = __pop_Variant2(__symbols);
         let __start = __sym0.0.clone();
-        let __end = __sym0.2.clone();
-        let __nt = super::__action1::<>(input, __sym0);
-        __symbols.push((__start, __Symbol::Variant1(__nt), __end));
-        (1, 2)
+        let __end = __sym2.2.clone();
+        let __nt = super::__action48::<>(input, __sym0, __sym1, __sym2);
+        __symbols.push((__start, __Symbol::Variant2(__nt), __end));
+        (3, 2)
     }
     pub(crate) fn __reduce5<
         'input,
@@ -560,16 +916,15 @@ mod __parse__Formula {
         _: core::marker::PhantomData<(&'input ())>,
     ) -> (usize, usize)
     {
-        // ImplAndEquivSubExpr = ImplAndEquivSubExpr, "->", OrSubExpr => ActionFn(2);
-        assert!(__symbols.len() >= 3);
-        let __sym2 = __pop_Variant1(__symbols);
-        let __sym1 = __pop_Variant0(__symbols);
-        let __sym0 = __pop_Variant1(__symbols);
+        // ("<" <Indent>) = "<", Indent => ActionFn(40);
+        assert!(__symbols.len() >= 2);
+        let __sym1 = __pop_Variant1(__symbols);
+        let __sym0 = __pop_Variant0(__symbols);
         let __start = __sym0.0.clone();
-        let __end = __sym2.2.clone();
-        let __nt = super::__action2::<>(input, __sym0, __sym1, __sym2);
+        let __end = __sym1.2.clone();
+        let __nt = super::__action40::<>(input, __sym0, __sym1);
         __symbols.push((__start, __Symbol::Variant1(__nt), __end));
-        (3, 3)
+        (2, 3)
     }
     pub(crate) fn __reduce6<
         'input,
@@ -580,16 +935,12 @@ mod __parse__Formula {
         _: core::marker::PhantomData<(&'input ())>,
     ) -> (usize, usize)
     {
-        // ImplAndEquivSubExpr = ImplAndEquivSubExpr, "<->", OrSubExpr => ActionFn(3);
-        assert!(__symbols.len() >= 3);
-        let __sym2 = __pop_Variant1(__symbols);
-        let __sym1 = __pop_Variant0(__symbols);
-        let __sym0 = __pop_Variant1(__symbols);
-        let __start = __sym0.0.clone();
-        let __end = __sym2.2.clone();
-        let __nt = super::__action3::<>(input, __sym0, __sym1, __sym2);
-        __symbols.push((__start, __Symbol::Variant1(__nt), __end));
-        (3, 3)
+        // ("<" <Indent>)* =  => ActionFn(38);
+        let __start = __lookahead_start.cloned().or_else(|| __symbols.last().map(|s| s.2.clone())).unwrap_or_default();
+        let __end = __start.clone();
+        let __nt = super::__action38::<>(input, &__start, &__end);
+        __symbols.push((__start, __Symbol::Variant2(__nt), __end));
+        (0, 4)
     }
     pub(crate) fn __reduce7<
         'input,
@@ -600,13 +951,13 @@ mod __parse__Formula {
         _: core::marker::PhantomData<(&'input ())>,
     ) -> (usize, usize)
     {
-        // ImplAndEquivSubExpr = OrSubExpr => ActionFn(4);
-        let __sym0 = __pop_Variant1(__symbols);
+        // ("<" <Indent>)* = ("<" <Indent>)+ => ActionFn(39);
+        let __sym0 = __pop_Variant2(__symbols);
         let __start = __sym0.0.clone();
         let __end = __sym0.2.clone();
-        let __nt = super::__action4::<>(input, __sym0);
-        __symbols.push((__start, __Symbol::Variant1(__nt), __end));
-        (1, 3)
+        let __nt = super::__action39::<>(input, __sym0);
+        __symbols.push((__start, __Symbol::Variant2(__nt), __end));
+        (1, 4)
     }
     pub(crate) fn __reduce8<
         'input,
@@ -617,13 +968,15 @@ mod __parse__Formula {
         _: core::marker::PhantomData<(&'input ())>,
     ) -> (usize, usize)
     {
-        // Indent = r#"[a-zA-Z0-9]+"# => ActionFn(16);
+        // ("<" <Indent>)+ = "<", Indent => ActionFn(51);
+        assert!(__symbols.len() >= 2);
+        let __sym1 = __pop_Variant1(__symbols);
         let __sym0 = __pop_Variant0(__symbols);
         let __start = __sym0.0.clone();
-        let __end = __sym0.2.clone();
-        let __nt = super::__action16::<>(input, __sym0);
+        let __end = __sym1.2.clone();
+        let __nt = super::__action51::<>(input, __sym0, __sym1);
         __symbols.push((__start, __Symbol::Variant2(__nt), __end));
-        (1, 4)
+        (2, 5)
     }
     pub(crate) fn __reduce9<
         'input,
@@ -634,15 +987,16 @@ mod __parse__Formula {
         _: core::marker::PhantomData<(&'input ())>,
     ) -> (usize, usize)
     {
-        // NotSubExpr = "!", NotSubExpr => ActionFn(9);
-        assert!(__symbols.len() >= 2);
-        let __sym1 = __pop_Variant1(__symbols);
-        let __sym0 = __pop_Variant0(__symbols);
+        // ("<" <Indent>)+ = ("<" <Indent>)+, "<", Indent => ActionFn(52);
+        assert!(__symbols.len() >= 3);
+        let __sym2 = __pop_Variant1(__symbols);
+        let __sym1 = __pop_Variant0(__symbols);
+        let __sym0 = __pop_Variant2(__symbols);
         let __start = __sym0.0.clone();
-        let __end = __sym1.2.clone();
-        let __nt = super::__action9::<>(input, __sym0, __sym1);
-        __symbols.push((__start, __Symbol::Variant1(__nt), __end));
-        (2, 5)
+        let __end = __sym2.2.clone();
+        let __nt = super::__action52::<>(input, __sym0, __sym1, __sym2);
+        __symbols.push((__start, __Symbol::Variant2(__nt), __end));
+        (3, 5)
     }
     pub(crate) fn __reduce10<
         'input,
@@ -653,13 +1007,16 @@ mod __parse__Formula {
         _: core::marker::PhantomData<(&'input ())>,
     ) -> (usize, usize)
     {
-        // NotSubExpr = Term => ActionFn(10);
-        let __sym0 = __pop_Variant1(__symbols);
+        // AndSubExpr = AndSubExpr, "&", NotSubExpr => ActionFn(22);
+        assert!(__symbols.len() >= 3);
+        let __sym2 = __pop_Variant3(__symbols);
+        let __sym1 = __pop_Variant0(__symbols);
+        let __sym0 = __pop_Variant3(__symbols);
         let __start = __sym0.0.clone();
-        let __end = __sym0.2.clone();
-        let __nt = super::__action10::<>(input, __sym0);
-        __symbols.push((__start, __Symbol::Variant1(__nt), __end));
-        (1, 5)
+        let __end = __sym2.2.clone();
+        let __nt = super::__action22::<>(input, __sym0, __sym1, __sym2);
+        __symbols.push((__start, __Symbol::Variant3(__nt), __end));
+        (3, 6)
     }
     pub(crate) fn __reduce11<
         'input,
@@ -670,16 +1027,13 @@ mod __parse__Formula {
         _: core::marker::PhantomData<(&'input ())>,
     ) -> (usize, usize)
     {
-        // OrSubExpr = OrSubExpr, "|", AndSubExpr => ActionFn(5);
-        assert!(__symbols.len() >= 3);
-        let __sym2 = __pop_Variant1(__symbols);
-        let __sym1 = __pop_Variant0(__symbols);
-        let __sym0 = __pop_Variant1(__symbols);
+        // AndSubExpr = NotSubExpr => ActionFn(23);
+        let __sym0 = __pop_Variant3(__symbols);
         let __start = __sym0.0.clone();
-        let __end = __sym2.2.clone();
-        let __nt = super::__action5::<>(input, __sym0, __sym1, __sym2);
-        __symbols.push((__start, __Symbol::Variant1(__nt), __end));
-        (3, 6)
+        let __end = __sym0.2.clone();
+        let __nt = super::__action23::<>(input, __sym0);
+        __symbols.push((__start, __Symbol::Variant3(__nt), __end));
+        (1, 6)
     }
     pub(crate) fn __reduce12<
         'input,
@@ -690,13 +1044,13 @@ mod __parse__Formula {
         _: core::marker::PhantomData<(&'input ())>,
     ) -> (usize, usize)
     {
-        // OrSubExpr = AndSubExpr => ActionFn(6);
-        let __sym0 = __pop_Variant1(__symbols);
+        // Binder = "exists" => ActionFn(15);
+        let __sym0 = __pop_Variant0(__symbols);
         let __start = __sym0.0.clone();
         let __end = __sym0.2.clone();
-        let __nt = super::__action6::<>(input, __sym0);
-        __symbols.push((__start, __Symbol::Variant1(__nt), __end));
-        (1, 6)
+        let __nt = super::__action15::<>(input, __sym0);
+        __symbols.push((__start, __Symbol::Variant4(__nt), __end));
+        (1, 7)
     }
     pub(crate) fn __reduce13<
         'input,
@@ -707,12 +1061,12 @@ mod __parse__Formula {
         _: core::marker::PhantomData<(&'input ())>,
     ) -> (usize, usize)
     {
-        // Term = Indent => ActionFn(11);
-        let __sym0 = __pop_Variant2(__symbols);
+        // Binder = "forall" => ActionFn(16);
+        let __sym0 = __pop_Variant0(__symbols);
         let __start = __sym0.0.clone();
         let __end = __sym0.2.clone();
-        let __nt = super::__action11::<>(input, __sym0);
-        __symbols.push((__start, __Symbol::Variant1(__nt), __end));
+        let __nt = super::__action16::<>(input, __sym0);
+        __symbols.push((__start, __Symbol::Variant4(__nt), __end));
         (1, 7)
     }
     pub(crate) fn __reduce14<
@@ -724,13 +1078,13 @@ mod __parse__Formula {
         _: core::marker::PhantomData<(&'input ())>,
     ) -> (usize, usize)
     {
-        // Term = Constant => ActionFn(12);
-        let __sym0 = __pop_Variant1(__symbols);
+        // Constant = "T" => ActionFn(29);
+        let __sym0 = __pop_Variant0(__symbols);
         let __start = __sym0.0.clone();
         let __end = __sym0.2.clone();
-        let __nt = super::__action12::<>(input, __sym0);
-        __symbols.push((__start, __Symbol::Variant1(__nt), __end));
-        (1, 7)
+        let __nt = super::__action29::<>(input, __sym0);
+        __symbols.push((__start, __Symbol::Variant3(__nt), __end));
+        (1, 8)
     }
     pub(crate) fn __reduce15<
         'input,
@@ -741,50 +1095,797 @@ mod __parse__Formula {
         _: core::marker::PhantomData<(&'input ())>,
     ) -> (usize, usize)
     {
-        // Term = "(", Formula, ")" => ActionFn(13);
-        assert!(__symbols.len() >= 3);
-        let __sym2 = __pop_Variant0(__symbols);
-        let __sym1 = __pop_Variant1(__symbols);
+        // Constant = "F" => ActionFn(30);
         let __sym0 = __pop_Variant0(__symbols);
         let __start = __sym0.0.clone();
-        let __end = __sym2.2.clone();
-        let __nt = super::__action13::<>(input, __sym0, __sym1, __sym2);
-        __symbols.push((__start, __Symbol::Variant1(__nt), __end));
-        (3, 7)
-    }
-}
-pub use self::__parse__Formula::FormulaParser;
-#[cfg_attr(rustfmt, rustfmt_skip)]
-mod __intern_token {
-    #![allow(unused_imports)]
-    use crate::formula_parser::*;
-    #[allow(unused_extern_crates)]
-    extern crate lalrpop_util as __lalrpop_util;
-    #[allow(unused_imports)]
-    use self::__lalrpop_util::state_machine as __state_machine;
-    extern crate core;
-    extern crate alloc;
-    pub fn new_builder() -> __lalrpop_util::lexer::MatcherBuilder {
-        let __strs: &[(&str, bool)] = &[
-            ("^([0-9A-Za-z]+)", false),
-            ("^(!)", false),
-            ("^(\\&)", false),
-            ("^(\\()", false),
-            ("^(\\))", false),
-            ("^(\\->)", false),
-            ("^(<\\->)", false),
-            ("^(F)", false),
-            ("^(T)", false),
-            ("^(\\|)", false),
-            (r"^(\s*)", true),
-        ];
-        __lalrpop_util::lexer::MatcherBuilder::new(__strs.iter().copied()).unwrap()
+        let __end = __sym0.2.clone();
+        let __nt = super::__action30::<>(input, __sym0);
+        __symbols.push((__start, __Symbol::Variant3(__nt), __end));
+        (1, 8)
     }
-}
-pub(crate) use self::__lalrpop_util::lexer::Token;
+    pub(crate) fn __reduce16<
+        'input,
+    >(
+        input: &'input str,
+        __lookahead_start: Option<&usize>,
+        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
+        _: core::marker::PhantomData<(&'input ())>,
+    ) -> (usize, usize)
+    {
+        // Formula = OpenImplAndEquivSubExpr => ActionFn(3);
+        let __sym0 = __pop_Variant3(__symbols);
+        let __start = __sym0.0.clone();
+        let __end = __sym0.2.clone();
+        let __nt = super::__action3::<>(input, __sym0);
+        __symbols.push((__start, __Symbol::Variant3(__nt), __end));
+        (1, 9)
+    }
+    pub(crate) fn __reduce17<
+        'input,
+    >(
+        input: &'input str,
+        __lookahead_start: Option<&usize>,
+        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
+        _: core::marker::PhantomData<(&'input ())>,
+    ) -> (usize, usize)
+    {
+        // Formula = ImplAndEquivSubExpr => ActionFn(4);
+        let __sym0 = __pop_Variant3(__symbols);
+        let __start = __sym0.0.clone();
+        let __end = __sym0.2.clone();
+        let __nt = super::__action4::<>(input, __sym0);
+        __symbols.push((__start, __Symbol::Variant3(__nt), __end));
+        (1, 9)
+    }
+    pub(crate) fn __reduce18<
+        'input,
+    >(
+        input: &'input str,
+        __lookahead_start: Option<&usize>,
+        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
+        _: core::marker::PhantomData<(&'input ())>,
+    ) -> (usize, usize)
+    {
+        // ImplAndEquivSubExpr = ImplAndEquivSubExpr, "->", OrSubExpr => ActionFn(17);
+        assert!(__symbols.len() >= 3);
+        let __sym2 = __pop_Variant3(__symbols);
+        let __sym1 = __pop_Variant0(__symbols);
+        let __sym0 = __pop_Variant3(__symbols);
+        let __start = __sym0.0.clone();
+        let __end = __sym2.2.clone();
+        let __nt = super::__action17::<>(input, __sym0, __sym1, __sym2);
+        __symbols.push((__start, __Symbol::Variant3(__nt), __end));
+        (3, 10)
+    }
+    pub(crate) fn __reduce19<
+        'input,
+    >(
+        input: &'input str,
+        __lookahead_start: Option<&usize>,
+        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
+        _: core::marker::PhantomData<(&'input ())>,
+    ) -> (usize, usize)
+    {
+        // ImplAndEquivSubExpr = ImplAndEquivSubExpr, "<->", OrSubExpr => ActionFn(18);
+        assert!(__symbols.len() >= 3);
+        let __sym2 = __pop_Variant3(__symbols);
+        let __sym1 = __pop_Variant0(__symbols);
+        let __sym0 = __pop_Variant3(__symbols);
+        let __start = __sym0.0.clone();
+        let __end = __sym2.2.clone();
+        let __nt = super::__action18::<>(input, __sym0, __sym1, __sym2);
+        __symbols.push((__start, __Symbol::Variant3(__nt), __end));
+        (3, 10)
+    }
+    pub(crate) fn __reduce20<
+        'input,
+    >(
+        input: &'input str,
+        __lookahead_start: Option<&usize>,
+        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
+        _: core::marker::PhantomData<(&'input ())>,
+    ) -> (usize, usize)
+    {
+        // ImplAndEquivSubExpr = OrSubExpr => ActionFn(19);
+        let __sym0 = __pop_Variant3(__symbols);
+        let __start = __sym0.0.clone();
+        let __end = __sym0.2.clone();
+        let __nt = super::__action19::<>(input, __sym0);
+        __symbols.push((__start, __Symbol::Variant3(__nt), __end));
+        (1, 10)
+    }
+    pub(crate) fn __reduce21<
+        'input,
+    >(
+        input: &'input str,
+        __lookahead_start: Option<&usize>,
+        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
+        _: core::marker::PhantomData<(&'input ())>,
+    ) -> (usize, usize)
+    {
+        // Indent = r#"[a-zA-Z0-9]+"# => ActionFn(31);
+        let __sym0 = __pop_Variant0(__symbols);
+        let __start = __sym0.0.clone();
+        let __end = __sym0.2.clone();
+        let __nt = super::__action31::<>(input, __sym0);
+        __symbols.push((__start, __Symbol::Variant1(__nt), __end));
+        (1, 11)
+    }
+    pub(crate) fn __reduce22<
+        'input,
+    >(
+        input: &'input str,
+        __lookahead_start: Option<&usize>,
+        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
+        _: core::marker::PhantomData<(&'input ())>,
+    ) -> (usize, usize)
+    {
+        // Indent = "order" => ActionFn(32);
+        let __sym0 = __pop_Variant0(__symbols);
+        let __start = __sym0.0.clone();
+        let __end = __sym0.2.clone();
+        let __nt = super::__action32::<>(input, __sym0);
+        __symbols.push((__start, __Symbol::Variant1(__nt), __end));
+        (1, 11)
+    }
+    pub(crate) fn __reduce23<
+        'input,
+    >(
+        input: &'input str,
+        __lookahead_start: Option<&usize>,
+        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
+        _: core::marker::PhantomData<(&'input ())>,
+    ) -> (usize, usize)
+    {
+        // Indent = "exists" => ActionFn(33);
+        let __sym0 = __pop_Variant0(__symbols);
+        let __start = __sym0.0.clone();
+        let __end = __sym0.2.clone();
+        let __nt = super::__action33::<>(input, __sym0);
+        __symbols.push((__start, __Symbol::Variant1(__nt), __end));
+        (1, 11)
+    }
+    pub(crate) fn __reduce24<
+        'input,
+    >(
+        input: &'input str,
+        __lookahead_start: Option<&usize>,
+        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
+        _: core::marker::PhantomData<(&'input ())>,
+    ) -> (usize, usize)
+    {
+        // Indent = "forall" => ActionFn(34);
+        let __sym0 = __pop_Variant0(__symbols);
+        let __start = __sym0.0.clone();
+        let __end = __sym0.2.clone();
+        let __nt = super::__action34::<>(input, __sym0);
+        __symbols.push((__start, __Symbol::Variant1(__nt), __end));
+        (1, 11)
+    }
+    pub(crate) fn __reduce25<
+        'input,
+    >(
+        input: &'input str,
+        __lookahead_start: Option<&usize>,
+        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
+        _: core::marker::PhantomData<(&'input ())>,
+    ) -> (usize, usize)
+    {
+        // Input = OrderDeclaration, Formula => ActionFn(55);
+        assert!(__symbols.len() >= 2);
+        let __sym1 = __pop_Variant3(__symbols);
+        let __sym0 = __pop_Variant6(__symbols);
+        let __start = __sym0.0.clone();
+        let __end = __sym1.2.clone();
+        let __nt = super::__action55::<>(input, __sym0, __sym1);
+        __symbols.push((__start, __Symbol::Variant5(__nt), __end));
+        (2, 12)
+    }
+    pub(crate) fn __reduce26<
+        'input,
+    >(
+        input: &'input str,
+        __lookahead_start: Option<&usize>,
+        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
+        _: core::marker::PhantomData<(&'input ())>,
+    ) -> (usize, usize)
+    {
+        // Input = Formula => ActionFn(56);
+        let __sym0 = __pop_Variant3(__symbols);
+        let __start = __sym0.0.clone();
+        let __end = __sym0.2.clone();
+        let __nt = super::__action56::<>(input, __sym0);
+        __symbols.push((__start, __Symbol::Variant5(__nt), __end));
+        (1, 12)
+    }
+    pub(crate) fn __reduce27<
+        'input,
+    >(
+        input: &'input str,
+        __lookahead_start: Option<&usize>,
+        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
+        _: core::marker::PhantomData<(&'input ())>,
+    ) -> (usize, usize)
+    {
+        // NotSubExpr = "!", NotSubExpr => ActionFn(24);
+        assert!(__symbols.len() >= 2);
+        let __sym1 = __pop_Variant3(__symbols);
+        let __sym0 = __pop_Variant0(__symbols);
+        let __start = __sym0.0.clone();
+        let __end = __sym1.2.clone();
+        let __nt = super::__action24::<>(input, __sym0, __sym1);
+        __symbols.push((__start, __Symbol::Variant3(__nt), __end));
+        (2, 13)
+    }
+    pub(crate) fn __reduce28<
+        'input,
+    >(
+        input: &'input str,
+        __lookahead_start: Option<&usize>,
+        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
+        _: core::marker::PhantomData<(&'input ())>,
+    ) -> (usize, usize)
+    {
+        // NotSubExpr = Term => ActionFn(25);
+        let __sym0 = __pop_Variant3(__symbols);
+        let __start = __sym0.0.clone();
+        let __end = __sym0.2.clone();
+        let __nt = super::__action25::<>(input, __sym0);
+        __symbols.push((__start, __Symbol::Variant3(__nt), __end));
+        (1, 13)
+    }
+    pub(crate) fn __reduce29<
+        'input,
+    >(
+        input: &'input str,
+        __lookahead_start: Option<&usize>,
+        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
+        _: core::marker::PhantomData<(&'input ())>,
+    ) -> (usize, usize)
+    {
+        // OpenAndSubExpr = AndSubExpr, "&", OpenNotSubExpr => ActionFn(10);
+        assert!(__symbols.len() >= 3);
+        let __sym2 = __pop_Variant3(__symbols);
+        let __sym1 = __pop_Variant0(__symbols);
+        let __sym0 = __pop_Variant3(__symbols);
+        let __start = __sym0.0.clone();
+        let __end = __sym2.2.clone();
+        let __nt = super::__action10::<>(input, __sym0, __sym1, __sym2);
+        __symbols.push((__start, __Symbol::Variant3(__nt), __end));
+        (3, 14)
+    }
+    pub(crate) fn __reduce30<
+        'input,
+    >(
+        input: &'input str,
+        __lookahead_start: Option<&usize>,
+        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
+        _: core::marker::PhantomData<(&'input ())>,
+    ) -> (usize, usize)
+    {
+        // OpenAndSubExpr = OpenNotSubExpr => ActionFn(11);
+        let __sym0 = __pop_Variant3(__symbols);
+        let __start = __sym0.0.clone();
+        let __end = __sym0.2.clone();
+        let __nt = super::__action11::<>(input, __sym0);
+        __symbols.push((__start, __Symbol::Variant3(__nt), __end));
+        (1, 14)
+    }
+    pub(crate) fn __reduce31<
+        'input,
+    >(
+        input: &'input str,
+        __lookahead_start: Option<&usize>,
+        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
+        _: core::marker::PhantomData<(&'input ())>,
+    ) -> (usize, usize)
+    {
+        // OpenImplAndEquivSubExpr = ImplAndEquivSubExpr, "->", OpenOrSubExpr => ActionFn(5);
+        assert!(__symbols.len() >= 3);
+        let __sym2 = __pop_Variant3(__symbols);
+        let __sym1 = __pop_Variant0(__symbols);
+        let __sym0 = __pop_Variant3(__symbols);
+        let __start = __sym0.0.clone();
+        let __end = __sym2.2.clone();
+        let __nt = super::__action5::<>(input, __sym0, __sym1, __sym2);
+        __symbols.push((__start, __Symbol::Variant3(__nt), __end));
+        (3, 15)
+    }
+    pub(crate) fn __reduce32<
+        'input,
+    >(
+        input: &'input str,
+        __lookahead_start: Option<&usize>,
+        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
+        _: core::marker::PhantomData<(&'input ())>,
+    ) -> (usize, usize)
+    {
+        // OpenImplAndEquivSubExpr = ImplAndEquivSubExpr, "<->", OpenOrSubExpr => ActionFn(6);
+        assert!(__symbols.len() >= 3);
+        let __sym2 = __pop_Variant3(__symbols);
+        let __sym1 = __pop_Variant0(__symbols);
+        let __sym0 = __pop_Variant3(__symbols);
+        let __start = __sym0.0.clone();
+        let __end = __sym2.2.clone();
+        let __nt = super::__action6::<>(input, __sym0, __sym1, __sym2);
+        __symbols.push((__start, __Symbol::Variant3(__nt), __end));
+        (3, 15)
+    }
+    pub(crate) fn __reduce33<
+        'input,
+    >(
+        input: &'input str,
+        __lookahead_start: Option<&usize>,
+        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
+        _: core::marker::PhantomData<(&'input ())>,
+    ) -> (usize, usize)
+    {
+        // OpenImplAndEquivSubExpr = OpenOrSubExpr => ActionFn(7);
+        let __sym0 = __pop_Variant3(__symbols);
+        let __start = __sym0.0.clone();
+        let __end = __sym0.2.clone();
+        let __nt = super::__action7::<>(input, __sym0);
+        __symbols.push((__start, __Symbol::Variant3(__nt), __end));
+        (1, 15)
+    }
+    pub(crate) fn __reduce34<
+        'input,
+    >(
+        input: &'input str,
+        __lookahead_start: Option<&usize>,
+        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
+        _: core::marker::PhantomData<(&'input ())>,
+    ) -> (usize, usize)
+    {
+        // OpenNotSubExpr = "!", OpenNotSubExpr => ActionFn(12);
+        assert!(__symbols.len() >= 2);
+        let __sym1 = __pop_Variant3(__symbols);
+        let __sym0 = __pop_Variant0(__symbols);
+        let __start = __sym0.0.clone();
+        let __end = __sym1.2.clone();
+        let __nt = super::__action12::<>(input, __sym0, __sym1);
+        __symbols.push((__start, __Symbol::Variant3(__nt), __end));
+        (2, 16)
+    }
+    pub(crate) fn __reduce35<
+        'input,
+    >(
+        input: &'input str,
+        __lookahead_start: Option<&usize>,
+        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
+        _: core::marker::PhantomData<(&'input ())>,
+    ) -> (usize, usize)
+    {
+        // OpenNotSubExpr = Quantified => ActionFn(13);
+        let __sym0 = __pop_Variant3(__symbols);
+        let __start = __sym0.0.clone();
+        let __end = __sym0.2.clone();
+        let __nt = super::__action13::<>(input, __sym0);
+        __symbols.push((__start, __Symbol::Variant3(__nt), __end));
+        (1, 16)
+    }
+    pub(crate) fn __reduce36<
+        'input,
+    >(
+        input: &'input str,
+        __lookahead_start: Option<&usize>,
+        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
+        _: core::marker::PhantomData<(&'input ())>,
+    ) -> (usize, usize)
+    {
+        // OpenOrSubExpr = OrSubExpr, "|", OpenAndSubExpr => ActionFn(8);
+        assert!(__symbols.len() >= 3);
+        let __sym2 = __pop_Variant3(__symbols);
+        let __sym1 = __pop_Variant0(__symbols);
+        let __sym0 = __pop_Variant3(__symbols);
+        let __start = __sym0.0.clone();
+        let __end = __sym2.2.clone();
+        let __nt = super::__action8::<>(input, __sym0, __sym1, __sym2);
+        __symbols.push((__start, __Symbol::Variant3(__nt), __end));
+        (3, 17)
+    }
+    pub(crate) fn __reduce37<
+        'input,
+    >(
+        input: &'input str,
+        __lookahead_start: Option<&usize>,
+        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
+        _: core::marker::PhantomData<(&'input ())>,
+    ) -> (usize, usize)
+    {
+        // OpenOrSubExpr = OpenAndSubExpr => ActionFn(9);
+        let __sym0 = __pop_Variant3(__symbols);
+        let __start = __sym0.0.clone();
+        let __end = __sym0.2.clone();
+        let __nt = super::__action9::<>(input, __sym0);
+        __symbols.push((__start, __Symbol::Variant3(__nt), __end));
+        (1, 17)
+    }
+    pub(crate) fn __reduce38<
+        'input,
+    >(
+        input: &'input str,
+        __lookahead_start: Option<&usize>,
+        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
+        _: core::marker::PhantomData<(&'input ())>,
+    ) -> (usize, usize)
+    {
+        // OrSubExpr = OrSubExpr, "|", AndSubExpr => ActionFn(20);
+        assert!(__symbols.len() >= 3);
+        let __sym2 = __pop_Variant3(__symbols);
+        let __sym1 = __pop_Variant0(__symbols);
+        let __sym0 = __pop_Variant3(__symbols);
+        let __start = __sym0.0.clone();
+        let __end = __sym2.2.clone();
+        let __nt = super::__action20::<>(input, __sym0, __sym1, __sym2);
+        __symbols.push((__start, __Symbol::Variant3(__nt), __end));
+        (3, 18)
+    }
+    pub(crate) fn __reduce39<
+        'input,
+    >(
+        input: &'input str,
+        __lookahead_start: Option<&usize>,
+        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
+        _: core::marker::PhantomData<(&'input ())>,
+    ) -> (usize, usize)
+    {
+        // OrSubExpr = AndSubExpr => ActionFn(21);
+        let __sym0 = __pop_Variant3(__symbols);
+        let __start = __sym0.0.clone();
+        let __end = __sym0.2.clone();
+        let __nt = super::__action21::<>(input, __sym0);
+        __symbols.push((__start, __Symbol::Variant3(__nt), __end));
+        (1, 18)
+    }
+    pub(crate) fn __reduce40<
+        'input,
+    >(
+        input: &'input str,
+        __lookahead_start: Option<&usize>,
+        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
+        _: core::marker::PhantomData<(&'input ())>,
+    ) -> (usize, usize)
+    {
+        // OrderDeclaration = "order", ":", Indent => ActionFn(53);
+        assert!(__symbols.len() >= 3);
+        let __sym2 = __pop_Variant1(__symbols);
+        let __sym1 = __pop_Variant0(__symbols);
+        let __sym0 = __pop_Variant0(__symbols);
+        let __start = __sym0.0.clone();
+        let __end = __sym2.2.clone();
+        let __nt = super::__action53::<>(input, __sym0, __sym1, __sym2);
+        __symbols.push((__start, __Symbol::Variant6(__nt), __end));
+        (3, 19)
+    }
+    pub(crate) fn __reduce41<
+        'input,
+    >(
+        input: &'input str,
+        __lookahead_start: Option<&usize>,
+        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
+        _: core::marker::PhantomData<(&'input ())>,
+    ) -> (usize, usize)
+    {
+        // OrderDeclaration = "order", ":", Indent, ("<" <Indent>)+ => ActionFn(54);
+        assert!(__symbols.len() >= 4);
+        let __sym3 = __pop_Variant2(__symbols);
+        let __sym2 = __pop_Variant1(__symbols);
+        let __sym1 = __pop_Variant0(__symbols);
+        let __sym0 = __pop_Variant0(__symbols);
+        let __start = __sym0.0.clone();
+        let __end = __sym3.2.clone();
+        let __nt = super::__action54::<>(input, __sym0, __sym1, __sym2, __sym3);
+        __symbols.push((__start, __Symbol::Variant6(__nt), __end));
+        (4, 19)
+    }
+    pub(crate) fn __reduce42<
+        'input,
+    >(
+        input: &'input str,
+        __lookahead_start: Option<&usize>,
+        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
+        _: core::marker::PhantomData<(&'input ())>,
+    ) -> (usize, usize)
+    {
+        // OrderDeclaration? = OrderDeclaration => ActionFn(41);
+        let __sym0 = __pop_Variant6(__symbols);
+        let __start = __sym0.0.clone();
+        let __end = __sym0.2.clone();
+        let __nt = super::__action41::<>(input, __sym0);
+        __symbols.push((__start, __Symbol::Variant7(__nt), __end));
+        (1, 20)
+    }
+    pub(crate) fn __reduce43<
+        'input,
+    >(
+        input: &'input str,
+        __lookahead_start: Option<&usize>,
+        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
+        _: core::marker::PhantomData<(&'input ())>,
+    ) -> (usize, usize)
+    {
+        // OrderDeclaration? =  => ActionFn(42);
+        let __start = __lookahead_start.cloned().or_else(|| __symbols.last().map(|s| s.2.clone())).unwrap_or_default();
+        let __end = __start.clone();
+        let __nt = super::__action42::<>(input, &__start, &__end);
+        __symbols.push((__start, __Symbol::Variant7(__nt), __end));
+        (0, 20)
+    }
+    pub(crate) fn __reduce44<
+        'input,
+    >(
+        input: &'input str,
+        __lookahead_start: Option<&usize>,
+        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
+        _: core::marker::PhantomData<(&'input ())>,
+    ) -> (usize, usize)
+    {
+        // Quantified = Binder, Indent, ".", Formula => ActionFn(49);
+        assert!(__symbols.len() >= 4);
+        let __sym3 = __pop_Variant3(__symbols);
+        let __sym2 = __pop_Variant0(__symbols);
+        let __sym1 = __pop_Variant1(__symbols);
+        let __sym0 = __pop_Variant4(__symbols);
+        let __start = __sym0.0.clone();
+        let __end = __sym3.2.clone();
+        let __nt = super::__action49::<>(input, __sym0, __sym1, __sym2, __sym3);
+        __symbols.push((__start, __Symbol::Variant3(__nt), __end));
+        (4, 21)
+    }
+    pub(crate) fn __reduce45<
+        'input,
+    >(
+        input: &'input str,
+        __lookahead_start: Option<&usize>,
+        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
+        _: core::marker::PhantomData<(&'input ())>,
+    ) -> (usize, usize)
+    {
+        // Quantified = Binder, Indent, ("," <Indent>)+, ".", Formula => ActionFn(50);
+        assert!(__symbols.len() >= 5);
+        let __sym4 = __pop_Variant3(__symbols);
+        let __sym3 = __pop_Variant0(__symbols);
+        let __sym2 = __pop_Variant2(__symbols);
+        let __sym1 = __pop_Variant1(__symbols);
+        let __sym0 = __pop_Variant4(__symbols);
+        let __start = __sym0.0.clone();
+        let __end = __sym4.2.clone();
+        let __nt = super::__action50::<>(input, __sym0, __sym1, __sym2, __sym3, __sym4);
+        __symbols.push((__start, __Symbol::Variant3(__nt), __end));
+        (5, 21)
+    }
+    pub(crate) fn __reduce46<
+        'input,
+    >(
+        input: &'input str,
+        __lookahead_start: Option<&usize>,
+        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
+        _: core::marker::PhantomData<(&'input ())>,
+    ) -> (usize, usize)
+    {
+        // Term = Indent => ActionFn(26);
+        let __sym0 = __pop_Variant1(__symbols);
+        let __start = __sym0.0.clone();
+        let __end = __sym0.2.clone();
+        let __nt = super::__action26::<>(input, __sym0);
+        __symbols.push((__start, __Symbol::Variant3(__nt), __end));
+        (1, 22)
+    }
+    pub(crate) fn __reduce47<
+        'input,
+    >(
+        input: &'input str,
+        __lookahead_start: Option<&usize>,
+        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
+        _: core::marker::PhantomData<(&'input ())>,
+    ) -> (usize, usize)
+    {
+        // Term = Constant => ActionFn(27);
+        let __sym0 = __pop_Variant3(__symbols);
+        let __start = __sym0.0.clone();
+        let __end = __sym0.2.clone();
+        let __nt = super::__action27::<>(input, __sym0);
+        __symbols.push((__start, __Symbol::Variant3(__nt), __end));
+        (1, 22)
+    }
+    pub(crate) fn __reduce48<
+        'input,
+    >(
+        input: &'input str,
+        __lookahead_start: Option<&usize>,
+        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
+        _: core::marker::PhantomData<(&'input ())>,
+    ) -> (usize, usize)
+    {
+        // Term = "(", Formula, ")" => ActionFn(28);
+        assert!(__symbols.len() >= 3);
+        let __sym2 = __pop_Variant0(__symbols);
+        let __sym1 = __pop_Variant3(__symbols);
+        let __sym0 = __pop_Variant0(__symbols);
+        let __start = __sym0.0.clone();
+        let __end = __sym2.2.clone();
+        let __nt = super::__action28::<>(input, __sym0, __sym1, __sym2);
+        __symbols.push((__start, __Symbol::Variant3(__nt), __end));
+        (3, 22)
+    }
+}
+pub use self::__parse__Input::InputParser;
+#[cfg_attr(rustfmt, rustfmt_skip)]
+mod __intern_token {
+    #![allow(unused_imports)]
+    use crate::formula_parser::*;
+    #[allow(unused_extern_crates)]
+    extern crate lalrpop_util as __lalrpop_util;
+    #[allow(unused_imports)]
+    use self::__lalrpop_util::state_machine as __state_machine;
+    extern crate core;
+    extern crate alloc;
+    pub fn new_builder() -> __lalrpop_util::lexer::MatcherBuilder {
+        let __strs: &[(&str, bool)] = &[
+            ("^([0-9A-Za-z]+)", false),
+            ("^(!)", false),
+            ("^(\\&)", false),
+            ("^(\\()", false),
+            ("^(\\))", false),
+            ("^(,)", false),
+            ("^(\\->)", false),
+            ("^(\\.)", false),
+            ("^(:)", false),
+            ("^(<)", false),
+            ("^(<\\->)", false),
+            ("^(F)", false),
+            ("^(T)", false),
+            ("^(exists)", false),
+            ("^(forall)", false),
+            ("^(order)", false),
+            ("^(\\|)", false),
+            (r"^(\s*)", true),
+        ];
+        __lalrpop_util::lexer::MatcherBuilder::new(__strs.iter().copied()).unwrap()
+    }
+}
+pub(crate) use self::__lalrpop_util::lexer::Token;
+
+#[allow(unused_variables)]
+fn __action0<
+    'input,
+>(
+    input: &'input str,
+    (_, __0, _): (usize, (Vec<String>, ParserNode<String>), usize),
+) -> (Vec<String>, ParserNode<String>)
+{
+    __0
+}
+
+#[allow(unused_variables)]
+fn __action1<
+    'input,
+>(
+    input: &'input str,
+    (_, o, _): (usize, core::option::Option<Vec<String>>, usize),
+    (_, f, _): (usize, ParserNode<String>, usize),
+) -> (Vec<String>, ParserNode<String>)
+{
+    (o.unwrap_or_default(), f)
+}
+
+#[allow(unused_variables)]
+fn __action2<
+    'input,
+>(
+    input: &'input str,
+    (_, _, _): (usize, &'input str, usize),
+    (_, _, _): (usize, &'input str, usize),
+    (_, first, _): (usize, String, usize),
+    (_, rest, _): (usize, alloc::vec::Vec<String>, usize),
+) -> Vec<String>
+{
+    {
+        let mut order = vec![first];
+        order.extend(rest);
+        order
+    }
+}
+
+#[allow(unused_variables)]
+fn __action3<
+    'input,
+>(
+    input: &'input str,
+    (_, __0, _): (usize, ParserNode<String>, usize),
+) -> ParserNode<String>
+{
+    __0
+}
+
+#[allow(unused_variables)]
+fn __action4<
+    'input,
+>(
+    input: &'input str,
+    (_, __0, _): (usize, ParserNode<String>, usize),
+) -> ParserNode<String>
+{
+    __0
+}
+
+#[allow(unused_variables)]
+fn __action5<
+    'input,
+>(
+    input: &'input str,
+    (_, l, _): (usize, ParserNode<String>, usize),
+    (_, _, _): (usize, &'input str, usize),
+    (_, r, _): (usize, ParserNode<String>, usize),
+) -> ParserNode<String>
+{
+    ParserNode::Binary(BinaryOperation::Implication, (Box::new(l), Box::new(r)))
+}
+
+#[allow(unused_variables)]
+fn __action6<
+    'input,
+>(
+    input: &'input str,
+    (_, l, _): (usize, ParserNode<String>, usize),
+    (_, _, _): (usize, &'input str, usize),
+    (_, r, _): (usize, ParserNode<String>, usize),
+) -> ParserNode<String>
+{
+    ParserNode::Binary(BinaryOperation::Equivalence, (Box::new(l), Box::new(r)))
+}
+
+#[allow(unused_variables)]
+fn __action7<
+    'input,
+>(
+    input: &'input str,
+    (_, __0, _): (usize, ParserNode<String>, usize),
+) -> ParserNode<String>
+{
+    __0
+}
+
+#[allow(unused_variables)]
+fn __action8<
+    'input,
+>(
+    input: &'input str,
+    (_, l, _): (usize, ParserNode<String>, usize),
+    (_, _, _): (usize, &'input str, usize),
+    (_, r, _): (usize, ParserNode<String>, usize),
+) -> ParserNode<String>
+{
+    ParserNode::Binary(BinaryOperation::Or, (Box::new(l), Box::new(r)))
+}
+
+#[allow(unused_variables)]
+fn __action9<
+    'input,
+>(
+    input: &'input str,
+    (_, __0, _): (usize, ParserNode<String>, usize),
+) -> ParserNode<String>
+{
+    __0
+}
+
+#[allow(unused_variables)]
+fn __action10<
+    'input,
+>(
+    input: &'input str,
+    (_, l, _): (usize, ParserNode<String>, usize),
+    (_, _, _): (usize, &'input str, usize),
+    (_, r, _): (usize, ParserNode<String>, usize),
+) -> ParserNode<String>
+{
+    ParserNode::Binary(BinaryOperation::And, (Box::new(l), Box::new(r)))
+}
 
 #[allow(unused_variables)]
-fn __action0<
+fn __action11<
     'input,
 >(
     input: &'input str,
@@ -795,7 +1896,19 @@ fn __action0<
 }
 
 #[allow(unused_variables)]
-fn __action1<
+fn __action12<
+    'input,
+>(
+    input: &'input str,
+    (_, _, _): (usize, &'input str, usize),
+    (_, operand, _): (usize, ParserNode<String>, usize),
+) -> ParserNode<String>
+{
+    ParserNode::Unary(UnaryOperation::Not, Box::new(operand))
+}
+
+#[allow(unused_variables)]
+fn __action13<
     'input,
 >(
     input: &'input str,
@@ -806,7 +1919,48 @@ fn __action1<
 }
 
 #[allow(unused_variables)]
-fn __action2<
+fn __action14<
+    'input,
+>(
+    input: &'input str,
+    (_, q, _): (usize, Quantifier, usize),
+    (_, first, _): (usize, String, usize),
+    (_, rest, _): (usize, alloc::vec::Vec<String>, usize),
+    (_, _, _): (usize, &'input str, usize),
+    (_, f, _): (usize, ParserNode<String>, usize),
+) -> ParserNode<String>
+{
+    {
+        let mut variables = vec![first];
+        variables.extend(rest);
+        ParserNode::Quantified(q, variables, Box::new(f))
+    }
+}
+
+#[allow(unused_variables)]
+fn __action15<
+    'input,
+>(
+    input: &'input str,
+    (_, __0, _): (usize, &'input str, usize),
+) -> Quantifier
+{
+    Quantifier::Exists
+}
+
+#[allow(unused_variables)]
+fn __action16<
+    'input,
+>(
+    input: &'input str,
+    (_, __0, _): (usize, &'input str, usize),
+) -> Quantifier
+{
+    Quantifier::Forall
+}
+
+#[allow(unused_variables)]
+fn __action17<
     'input,
 >(
     input: &'input str,
@@ -819,7 +1973,7 @@ fn __action2<
 }
 
 #[allow(unused_variables)]
-fn __action3<
+fn __action18<
     'input,
 >(
     input: &'input str,
@@ -832,7 +1986,7 @@ fn __action3<
 }
 
 #[allow(unused_variables)]
-fn __action4<
+fn __action19<
     'input,
 >(
     input: &'input str,
@@ -843,7 +1997,7 @@ fn __action4<
 }
 
 #[allow(unused_variables)]
-fn __action5<
+fn __action20<
     'input,
 >(
     input: &'input str,
@@ -856,7 +2010,7 @@ fn __action5<
 }
 
 #[allow(unused_variables)]
-fn __action6<
+fn __action21<
     'input,
 >(
     input: &'input str,
@@ -867,7 +2021,7 @@ fn __action6<
 }
 
 #[allow(unused_variables)]
-fn __action7<
+fn __action22<
     'input,
 >(
     input: &'input str,
@@ -880,7 +2034,7 @@ fn __action7<
 }
 
 #[allow(unused_variables)]
-fn __action8<
+fn __action23<
     'input,
 >(
     input: &'input str,
@@ -891,7 +2045,7 @@ fn __action8<
 }
 
 #[allow(unused_variables)]
-fn __action9<
+fn __action24<
     'input,
 >(
     input: &'input str,
@@ -903,7 +2057,7 @@ fn __action9<
 }
 
 #[allow(unused_variables)]
-fn __action10<
+fn __action25<
     'input,
 >(
     input: &'input str,
@@ -914,7 +2068,7 @@ fn __action10<
 }
 
 #[allow(unused_variables)]
-fn __action11<
+fn __action26<
     'input,
 >(
     input: &'input str,
@@ -925,7 +2079,7 @@ fn __action11<
 }
 
 #[allow(unused_variables)]
-fn __action12<
+fn __action27<
     'input,
 >(
     input: &'input str,
@@ -936,7 +2090,7 @@ fn __action12<
 }
 
 #[allow(unused_variables)]
-fn __action13<
+fn __action28<
     'input,
 >(
     input: &'input str,
@@ -949,7 +2103,7 @@ fn __action13<
 }
 
 #[allow(unused_variables)]
-fn __action14<
+fn __action29<
     'input,
 >(
     input: &'input str,
@@ -960,7 +2114,7 @@ fn __action14<
 }
 
 #[allow(unused_variables)]
-fn __action15<
+fn __action30<
     'input,
 >(
     input: &'input str,
@@ -971,7 +2125,7 @@ fn __action15<
 }
 
 #[allow(unused_variables)]
-fn __action16<
+fn __action31<
     'input,
 >(
     input: &'input str,
@@ -981,6 +2135,432 @@ fn __action16<
     String::from(i)
 }
 
+#[allow(unused_variables)]
+fn __action32<
+    'input,
+>(
+    input: &'input str,
+    (_, __0, _): (usize, &'input str, usize),
+) -> String
+{
+    String::from("order")
+}
+
+#[allow(unused_variables)]
+fn __action33<
+    'input,
+>(
+    input: &'input str,
+    (_, __0, _): (usize, &'input str, usize),
+) -> String
+{
+    String::from("exists")
+}
+
+#[allow(unused_variables)]
+fn __action34<
+    'input,
+>(
+    input: &'input str,
+    (_, __0, _): (usize, &'input str, usize),
+) -> String
+{
+    String::from("forall")
+}
+
+#[allow(unused_variables)]
+fn __action35<
+    'input,
+>(
+    input: &'input str,
+    __lookbehind: &usize,
+    __lookahead: &usize,
+) -> alloc::vec::Vec<String>
+{
+    alloc::vec![]
+}
+
+#[allow(unused_variables)]
+fn __action36<
+    'input,
+>(
+    input: &'input str,
+    (_, v, _): (usize, alloc::vec::Vec<String>, usize),
+) -> alloc::vec::Vec<String>
+{
+    v
+}
+
+#[allow(unused_variables)]
+fn __action37<
+    'input,
+>(
+    input: &'input str,
+    (_, _, _): (usize, &'input str, usize),
+    (_, __0, _): (usize, String, usize),
+) -> String
+{
+    __0
+}
+
+#[allow(unused_variables)]
+fn __action38<
+    'input,
+>(
+    input: &'input str,
+    __lookbehind: &usize,
+    __lookahead: &usize,
+) -> alloc::vec::Vec<String>
+{
+    alloc::vec![]
+}
+
+#[allow(unused_variables)]
+fn __action39<
+    'input,
+>(
+    input: &'input str,
+    (_, v, _): (usize, alloc::vec::Vec<String>, usize),
+) -> alloc::vec::Vec<String>
+{
+    v
+}
+
+#[allow(unused_variables)]
+fn __action40<
+    'input,
+>(
+    input: &'input str,
+    (_, _, _): (usize, &'input str, usize),
+    (_, __0, _): (usize, String, usize),
+) -> String
+{
+    __0
+}
+
+#[allow(unused_variables)]
+fn __action41<
+    'input,
+>(
+    input: &'input str,
+    (_, __0, _): (usize, Vec<String>, usize),
+) -> core::option::Option<Vec<String>>
+{
+    Some(__0)
+}
+
+#[allow(unused_variables)]
+fn __action42<
+    'input,
+>(
+    input: &'input str,
+    __lookbehind: &usize,
+    __lookahead: &usize,
+) -> core::option::Option<Vec<String>>
+{
+    None
+}
+
+#[allow(unused_variables)]
+fn __action43<
+    'input,
+>(
+    input: &'input str,
+    (_, __0, _): (usize, String, usize),
+) -> alloc::vec::Vec<String>
+{
+    alloc::vec![__0]
+}
+
+#[allow(unused_variables)]
+fn __action44<
+    'input,
+>(
+    input: &'input str,
+    (_, v, _): (usize, alloc::vec::Vec<String>, usize),
+    (_, e, _): (usize, String, usize),
+) -> alloc::vec::Vec<String>
+{
+    { let mut v = v; v.push(e); v }
+}
+
+#[allow(unused_variables)]
+fn __action45<
+    'input,
+>(
+    input: &'input str,
+    (_, __0, _): (usize, String, usize),
+) -> alloc::vec::Vec<String>
+{
+    alloc::vec![__0]
+}
+
+#[allow(unused_variables)]
+fn __action46<
+    'input,
+>(
+    input: &'input str,
+    (_, v, _): (usize, alloc::vec::Vec<String>, usize),
+    (_, e, _): (usize, String, usize),
+) -> alloc::vec::Vec<String>
+{
+    { let mut v = v; v.push(e); v }
+}
+
+#[allow(unused_variables)]
+fn __action47<
+    'input,
+>(
+    input: &'input str,
+    __0: (usize, &'input str, usize),
+    __1: (usize, String, usize),
+) -> alloc::vec::Vec<String>
+{
+    let __start0 = __0.0.clone();
+    let __end0 = __1.2.clone();
+    let __temp0 = __action37(
+        input,
+        __0,
+        __1,
+    );
+    let __temp0 = (__start0, __temp0, __end0);
+    __action45(
+        input,
+        __temp0,
+    )
+}
+
+#[allow(unused_variables)]
+fn __action48<
+    'input,
+>(
+    input: &'input str,
+    __0: (usize, alloc::vec::Vec<String>, usize),
+    __1: (usize, &'input str, usize),
+    __2: (usize, String, usize),
+) -> alloc::vec::Vec<String>
+{
+    let __start0 = __1.0.clone();
+    let __end0 = __2.2.clone();
+    let __temp0 = __action37(
+        input,
+        __1,
+        __2,
+    );
+    let __temp0 = (__start0, __temp0, __end0);
+    __action46(
+        input,
+        __0,
+        __temp0,
+    )
+}
+
+#[allow(unused_variables)]
+fn __action49<
+    'input,
+>(
+    input: &'input str,
+    __0: (usize, Quantifier, usize),
+    __1: (usize, String, usize),
+    __2: (usize, &'input str, usize),
+    __3: (usize, ParserNode<String>, usize),
+) -> ParserNode<String>
+{
+    let __start0 = __1.2.clone();
+    let __end0 = __2.0.clone();
+    let __temp0 = __action35(
+        input,
+        &__start0,
+        &__end0,
+    );
+    let __temp0 = (__start0, __temp0, __end0);
+    __action14(
+        input,
+        __0,
+        __1,
+        __temp0,
+        __2,
+        __3,
+    )
+}
+
+#[allow(unused_variables)]
+fn __action50<
+    'input,
+>(
+    input: &'input str,
+    __0: (usize, Quantifier, usize),
+    __1: (usize, String, usize),
+    __2: (usize, alloc::vec::Vec<String>, usize),
+    __3: (usize, &'input str, usize),
+    __4: (usize, ParserNode<String>, usize),
+) -> ParserNode<String>
+{
+    let __start0 = __2.0.clone();
+    let __end0 = __2.2.clone();
+    let __temp0 = __action36(
+        input,
+        __2,
+    );
+    let __temp0 = (__start0, __temp0, __end0);
+    __action14(
+        input,
+        __0,
+        __1,
+        __temp0,
+        __3,
+        __4,
+    )
+}
+
+#[allow(unused_variables)]
+fn __action51<
+    'input,
+>(
+    input: &'input str,
+    __0: (usize, &'input str, usize),
+    __1: (usize, String, usize),
+) -> alloc::vec::Vec<String>
+{
+    let __start0 = __0.0.clone();
+    let __end0 = __1.2.clone();
+    let __temp0 = __action40(
+        input,
+        __0,
+        __1,
+    );
+    let __temp0 = (__start0, __temp0, __end0);
+    __action43(
+        input,
+        __temp0,
+    )
+}
+
+#[allow(unused_variables)]
+fn __action52<
+    'input,
+>(
+    input: &'input str,
+    __0: (usize, alloc::vec::Vec<String>, usize),
+    __1: (usize, &'input str, usize),
+    __2: (usize, String, usize),
+) -> alloc::vec::Vec<String>
+{
+    let __start0 = __1.0.clone();
+    let __end0 = __2.2.clone();
+    let __temp0 = __action40(
+        input,
+        __1,
+        __2,
+    );
+    let __temp0 = (__start0, __temp0, __end0);
+    __action44(
+        input,
+        __0,
+        __temp0,
+    )
+}
+
+#[allow(unused_variables)]
+fn __action53<
+    'input,
+>(
+    input: &'input str,
+    __0: (usize, &'input str, usize),
+    __1: (usize, &'input str, usize),
+    __2: (usize, String, usize),
+) -> Vec<String>
+{
+    let __start0 = __2.2.clone();
+    let __end0 = __2.2.clone();
+    let __temp0 = __action38(
+        input,
+        &__start0,
+        &__end0,
+    );
+    let __temp0 = (__start0, __temp0, __end0);
+    __action2(
+        input,
+        __0,
+        __1,
+        __2,
+        __temp0,
+    )
+}
+
+#[allow(unused_variables)]
+fn __action54<
+    'input,
+>(
+    input: &'input str,
+    __0: (usize, &'input str, usize),
+    __1: (usize, &'input str, usize),
+    __2: (usize, String, usize),
+    __3: (usize, alloc::vec::Vec<String>, usize),
+) -> Vec<String>
+{
+    let __start0 = __3.0.clone();
+    let __end0 = __3.2.clone();
+    let __temp0 = __action39(
+        input,
+        __3,
+    );
+    let __temp0 = (__start0, __temp0, __end0);
+    __action2(
+        input,
+        __0,
+        __1,
+        __2,
+        __temp0,
+    )
+}
+
+#[allow(unused_variables)]
+fn __action55<
+    'input,
+>(
+    input: &'input str,
+    __0: (usize, Vec<String>, usize),
+    __1: (usize, ParserNode<String>, usize),
+) -> (Vec<String>, ParserNode<String>)
+{
+    let __start0 = __0.0.clone();
+    let __end0 = __0.2.clone();
+    let __temp0 = __action41(
+        input,
+        __0,
+    );
+    let __temp0 = (__start0, __temp0, __end0);
+    __action1(
+        input,
+        __temp0,
+        __1,
+    )
+}
+
+#[allow(unused_variables)]
+fn __action56<
+    'input,
+>(
+    input: &'input str,
+    __0: (usize, ParserNode<String>, usize),
+) -> (Vec<String>, ParserNode<String>)
+{
+    let __start0 = __0.0.clone();
+    let __end0 = __0.0.clone();
+    let __temp0 = __action42(
+        input,
+        &__start0,
+        &__end0,
+    );
+    let __temp0 = (__start0, __temp0, __end0);
+    __action1(
+        input,
+        __temp0,
+        __0,
+    )
+}
+
 pub trait __ToTriple<'input, >
 {
     fn to_triple(value: Self) -> Result<(usize,Token<'input>,usize), __lalrpop_util::ParseError<usize, Token<'input>, &'static str>>;
@@ -5,11 +5,14 @@
 //! 3. `|`
 //! 4. `->`, `<->`
 pub use crate::BinaryOperation;
-pub use crate::Operation;
+pub use crate::LexerError;
+pub use crate::Quantifier;
 pub use crate::UnaryOperation;
 
 /// The module `grammar` is an LR(1) parser for propositional logic formula.
 /// This is supported by crate [`lalrpop`](https://github.com/lalrpop/lalrpop). The hand-written CFG is in file [grammar.lalrpop](src/formula_parser/grammar.lalrpop). The file [grammar.rs](src/formula_parser/grammar.rs) is generated by `lalrpop`.
+#[allow(clippy::all)]
+#[rustfmt::skip]
 mod grammar;
 
 #[derive(Debug)]
@@ -18,18 +21,40 @@ pub enum ParserNode<T> {
     Binary(BinaryOperation, (Box<ParserNode<T>>, Box<ParserNode<T>>)),
     Variable(T),
     Leaf(bool),
+    Quantified(Quantifier, Vec<T>, Box<ParserNode<T>>),
 }
 
-pub fn formula_parse<'input>(
-    formula: &'input str,
-) -> Result<
-    ParserNode<String>,
-    lalrpop_util::ParseError<usize, lalrpop_util::lexer::Token<'input>, &'static str>,
-> {
-    grammar::FormulaParser::new().parse(formula)
+/// Parses a formula optionally preceded by an order declaration like `order: a < b < c`,
+/// returning the declared order (empty if there is none) and the formula.
+pub fn input_parse(input: &str) -> Result<(Vec<String>, ParserNode<String>), LexerError<'_>> {
+    grammar::InputParser::new().parse(input)
 }
 
 #[test]
 fn parse() {
-    println!("{:?}", formula_parse("(a->b)&c").unwrap());
+    println!("{:?}", input_parse("(a->b)&c").unwrap().1);
+}
+
+#[test]
+fn quantifier() {
+    let (_, formula) = input_parse("exists x, y. x & y -> forall z. z").unwrap();
+    match formula {
+        ParserNode::Quantified(Quantifier::Exists, variables, body) => {
+            assert_eq!(variables, vec!["x", "y"]);
+            assert!(matches!(
+                *body,
+                ParserNode::Binary(BinaryOperation::Implication, _)
+            ));
+        }
+        _ => panic!("The binder should cover the whole formula"),
+    }
+    assert!(input_parse("exists & forall").is_ok());
+}
+
+#[test]
+fn order_declaration() {
+    let (order, _) = input_parse("order: b < a\n a & b").unwrap();
+    assert_eq!(order, vec!["b", "a"]);
+    let (order, _) = input_parse("order & a").unwrap();
+    assert!(order.is_empty());
 }
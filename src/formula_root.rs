@@ -0,0 +1,497 @@
+use std::{
+    collections::HashMap,
+    fmt::Display,
+    hash::{Hash, Hasher},
+    ops::{BitAnd, BitOr, BitXor, Not},
+    rc::Rc,
+};
+
+use crate::{binary_decision_diagram::*, BigUnsigned, Cubes, Manager, Minterms};
+
+/// The error of evaluating a formula under an assignment lacking a variable it needs.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct MissingVariable<T>(pub T);
+
+impl<T> Display for MissingVariable<T>
+where
+    T: Display,
+{
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "No value for the variable {}", self.0)
+    }
+}
+
+impl<T> std::error::Error for MissingVariable<T> where T: Display + std::fmt::Debug {}
+
+/// A formula built by a `Manager`. Its root node stays referenced in the diagram
+/// until the last clone of the `FormulaRoot` is dropped.
+pub struct FormulaRoot<T>(NodeHandler, Manager<T>);
+
+impl<T> FormulaRoot<T> {
+    pub(crate) fn new(node_handler: NodeHandler, manager: Manager<T>) -> FormulaRoot<T> {
+        manager.0.borrow_mut().diagram.reference(&node_handler);
+        FormulaRoot(node_handler, manager)
+    }
+
+    pub(crate) fn node_handler(&self) -> NodeHandler {
+        self.0
+    }
+
+    pub fn manager(&self) -> &Manager<T> {
+        &self.1
+    }
+
+    /// The number of nodes of the diagram representing the formula.
+    pub fn node_count(&self) -> usize {
+        self.1 .0.borrow().diagram.count_nodes(&[self.0])
+    }
+}
+
+impl<T> FormulaRoot<T>
+where
+    T: Eq + Hash + Clone,
+{
+    /// The number of assignments to `variables` satisfying the formula. The variables it
+    /// does not depend on are free. Panics if it depends on a variable not among them.
+    pub fn sat_count(&self, variables: impl IntoIterator<Item = T>) -> BigUnsigned {
+        let context = self.1 .0.borrow();
+        let mut names: Vec<T> = vec![];
+        for name in variables {
+            if !names.contains(&name) {
+                names.push(name);
+            }
+        }
+        // The variables the manager does not know count as free ones
+        let (known, unknown): (Vec<T>, Vec<T>) = names
+            .into_iter()
+            .partition(|name| context.symbol_table.contains_key(name));
+        let known: Vec<usize> = known
+            .iter()
+            .map(|name| context.symbol_table[name])
+            .collect();
+        context.diagram.sat_count(self.0, &known) << unknown.len()
+    }
+
+    /// The variables the formula depends on, in the variable order. A formula may mention
+    /// more, like `c` in `a | !a & b | a & c`.
+    pub fn support(&self) -> Vec<T> {
+        let context = self.1 .0.borrow();
+        let support = context.diagram.support(self.0);
+        support
+            .into_iter()
+            .map(|variable| context.inverse_table[variable].clone())
+            .collect()
+    }
+
+    /// The variables taking the same value in every model of the formula, with that value,
+    /// in the variable order. An unsatisfiable formula has none.
+    pub fn essential_variables(&self) -> Vec<(T, bool)> {
+        let context = self.1 .0.borrow();
+        let essential = context.diagram.essential_variables(self.0);
+        Self::named(essential, &context.inverse_table)
+    }
+
+    /// The ones of `variables` the formula does not depend on, which can be removed from it,
+    /// e.g. by quantifying them, without changing it.
+    pub fn removable_variables(&self, variables: impl IntoIterator<Item = T>) -> Vec<T> {
+        let support = self.support();
+        let mut removable: Vec<T> = vec![];
+        for name in variables {
+            if !support.contains(&name) && !removable.contains(&name) {
+                removable.push(name);
+            }
+        }
+        removable
+    }
+
+    /// The value of the formula under `assignment`, found by following the path it takes down
+    /// the diagram. Only the variables on that path need a value, so `eval` accepts some
+    /// assignments `eval_lanes` and `eval_batch` reject, as they need a value for every
+    /// variable the formula depends on.
+    pub fn eval(&self, assignment: &HashMap<T, bool>) -> Result<bool, MissingVariable<T>> {
+        let context = self.1 .0.borrow();
+        let assignment = context.by_index(assignment);
+        context
+            .diagram
+            .eval(self.0, &assignment)
+            .map_err(|variable| MissingVariable(context.inverse_table[variable].clone()))
+    }
+
+    /// The values of the formula under 64 assignments at once, the `i`th bit of the value of
+    /// each variable and of the result belonging to the `i`th assignment. Every variable the
+    /// formula depends on needs a value.
+    pub fn eval_lanes(&self, assignments: &HashMap<T, u64>) -> Result<u64, MissingVariable<T>> {
+        let context = self.1 .0.borrow();
+        let assignments = context.by_index(assignments);
+        context
+            .diagram
+            .eval_lanes(self.0, &assignments)
+            .map_err(|variable| MissingVariable(context.inverse_table[variable].clone()))
+    }
+
+    /// The values of the formula under each of `assignments`, evaluated 64 at a time by
+    /// `eval_lanes`. Every variable the formula depends on needs a value in each of them.
+    pub fn eval_batch(
+        &self,
+        assignments: &[HashMap<T, bool>],
+    ) -> Result<Vec<bool>, MissingVariable<T>> {
+        let support = self.support();
+        let mut values = Vec::with_capacity(assignments.len());
+        for chunk in assignments.chunks(64) {
+            let mut lanes = HashMap::new();
+            for name in &support {
+                let mut lane = 0;
+                for (i, assignment) in chunk.iter().enumerate() {
+                    let value = *assignment
+                        .get(name)
+                        .ok_or_else(|| MissingVariable(name.clone()))?;
+                    lane |= (value as u64) << i;
+                }
+                lanes.insert(name.clone(), lane);
+            }
+            let result = self.eval_lanes(&lanes)?;
+            values.extend((0..chunk.len()).map(|i| result >> i & 1 == 1));
+        }
+        Ok(values)
+    }
+
+    /// A satisfying cube of the formula, found in a single walk down the diagram, which
+    /// leaves the variables not in it free. `None` if the formula is unsatisfiable.
+    pub fn any_sat(&self) -> Option<Vec<(T, bool)>> {
+        let context = self.1 .0.borrow();
+        let cube = context.diagram.any_sat(self.0)?;
+        Some(Self::named(cube, &context.inverse_table))
+    }
+
+    /// The smallest satisfying assignment to the variables the formula depends on, comparing
+    /// their values in the variable order with false before true. `None` if unsatisfiable.
+    pub fn min_sat(&self) -> Option<Vec<(T, bool)>> {
+        let context = self.1 .0.borrow();
+        let assignment = context.diagram.min_sat(self.0)?;
+        Some(Self::named(assignment, &context.inverse_table))
+    }
+
+    /// The largest satisfying assignment to the variables the formula depends on, comparing
+    /// their values in the variable order with false before true. `None` if unsatisfiable.
+    pub fn max_sat(&self) -> Option<Vec<(T, bool)>> {
+        let context = self.1 .0.borrow();
+        let assignment = context.diagram.max_sat(self.0)?;
+        Some(Self::named(assignment, &context.inverse_table))
+    }
+
+    fn named(assignment: Vec<(usize, bool)>, inverse_table: &[T]) -> Vec<(T, bool)> {
+        assignment
+            .into_iter()
+            .map(|(variable, value)| (inverse_table[variable].clone(), value))
+            .collect()
+    }
+
+    /// The satisfying cubes of the formula, which leave the variables not in them free.
+    pub fn cubes(&self) -> Cubes<T> {
+        Cubes::new(self)
+    }
+
+    /// The satisfying assignments to `variables`, in their order. Panics if the formula
+    /// depends on a variable not among them.
+    pub fn minterms(&self, variables: impl IntoIterator<Item = T>) -> Minterms<T> {
+        Minterms::new(self, variables)
+    }
+}
+
+impl<T> Clone for FormulaRoot<T> {
+    fn clone(&self) -> Self {
+        Self::new(self.0, self.1.clone())
+    }
+}
+
+impl<T> Drop for FormulaRoot<T> {
+    fn drop(&mut self) {
+        self.1 .0.borrow_mut().diagram.dereference(&self.0);
+    }
+}
+
+/// Two formulas are equal iff they belong to the same manager and are equivalent,
+/// since every function has a unique node in a diagram.
+impl<T> PartialEq for FormulaRoot<T> {
+    fn eq(&self, other: &Self) -> bool {
+        Rc::ptr_eq(&self.1 .0, &other.1 .0) && self.0 == other.0
+    }
+}
+
+impl<T> Eq for FormulaRoot<T> {}
+
+impl<T> Hash for FormulaRoot<T> {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.0.hash(state);
+    }
+}
+
+impl<T> Not for &FormulaRoot<T>
+where
+    T: Eq + Hash + Clone,
+{
+    type Output = FormulaRoot<T>;
+
+    fn not(self) -> Self::Output {
+        self.1.not(self)
+    }
+}
+
+impl<T> Not for FormulaRoot<T>
+where
+    T: Eq + Hash + Clone,
+{
+    type Output = FormulaRoot<T>;
+
+    fn not(self) -> Self::Output {
+        self.1.not(&self)
+    }
+}
+
+// Implements a binary operator for both `&FormulaRoot` and `FormulaRoot` with a method of `Manager`
+macro_rules! impl_binary_operator {
+    ($trait: ident, $function: ident, $method: ident) => {
+        impl<T> $trait for &FormulaRoot<T>
+        where
+            T: Eq + Hash + Clone,
+        {
+            type Output = FormulaRoot<T>;
+
+            fn $function(self, rhs: Self) -> Self::Output {
+                self.1.$method(self, rhs)
+            }
+        }
+
+        impl<T> $trait for FormulaRoot<T>
+        where
+            T: Eq + Hash + Clone,
+        {
+            type Output = FormulaRoot<T>;
+
+            fn $function(self, rhs: Self) -> Self::Output {
+                self.1.$method(&self, &rhs)
+            }
+        }
+    };
+}
+
+impl_binary_operator!(BitAnd, bitand, and);
+impl_binary_operator!(BitOr, bitor, or);
+impl_binary_operator!(BitXor, bitxor, xor);
+
+// For Display
+// returns (index, flag)
+// `flag` is true iff the index is generated by this function call
+impl<T> FormulaRoot<T>
+where
+    T: Display,
+{
+    fn get_index(
+        diagram: &BinaryDecisionDiagram<usize>,
+        node_handler: &NodeHandler,
+        inverse_table: &[T],
+        f: &mut std::fmt::Formatter<'_>,
+        index: &mut u32,
+        visit_record: &mut HashMap<NodeHandler, u32>,
+    ) -> (u32, bool) {
+        match node_handler.get_element(diagram) {
+            Element::Variable(var_value) => {
+                if let Some(node_index) = visit_record.get(node_handler) {
+                    (*node_index, false)
+                } else {
+                    let var = inverse_table[*var_value].to_string();
+                    writeln!(f, "{index} [label=\"{var}\"]").unwrap();
+                    let node_index = *index;
+                    visit_record.insert(*node_handler, node_index);
+                    *index += 1;
+                    (node_index, true)
+                }
+            }
+            Element::Binary(value) => (value as u32, false),
+        }
+    }
+
+    pub fn generic_fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let context = self.1 .0.borrow();
+        let diagram = &context.diagram;
+        writeln!(f, "digraph{{")?;
+        match self.0.get_element(diagram) {
+            Element::Variable(_) => {
+                writeln!(f, r#"0 [label="false"]"#)?;
+                writeln!(f, r#"1 [label="true"]"#)?;
+                let mut index = 2;
+                Self::fmt_reclusive(
+                    diagram,
+                    &self.0,
+                    &context.inverse_table,
+                    f,
+                    &mut index,
+                    &mut HashMap::new(),
+                )?;
+            }
+            Element::Binary(value) => match value {
+                true => writeln!(f, r#"1 [label="true"]"#)?,
+                false => writeln!(f, r#"0 [label="false"]"#)?,
+            },
+        }
+
+        writeln!(f, "}}")?;
+        Ok(())
+    }
+
+    fn fmt_reclusive(
+        diagram: &BinaryDecisionDiagram<usize>,
+        node_handler: &NodeHandler,
+        inverse_table: &[T],
+        f: &mut std::fmt::Formatter<'_>,
+        index: &mut u32,
+        visit_record: &mut HashMap<NodeHandler, u32>,
+    ) -> std::fmt::Result {
+        match node_handler.get_element(diagram) {
+            Element::Variable(_) => {
+                let (parent_index, _) =
+                    Self::get_index(diagram, node_handler, inverse_table, f, index, visit_record);
+                let ((left_index, left_recursive_flag), (right_index, right_recursive_flag)) = (
+                    Self::get_index(
+                        diagram,
+                        &node_handler.get_child(diagram, BinaryIndex::Left).unwrap(),
+                        inverse_table,
+                        f,
+                        index,
+                        visit_record,
+                    ),
+                    Self::get_index(
+                        diagram,
+                        &node_handler.get_child(diagram, BinaryIndex::Right).unwrap(),
+                        inverse_table,
+                        f,
+                        index,
+                        visit_record,
+                    ),
+                );
+                writeln!(f, "{parent_index} -> {left_index} [label=\"0\"]")?;
+                writeln!(f, "{parent_index} -> {right_index} [label=\"1\"]")?;
+                if left_recursive_flag {
+                    Self::fmt_reclusive(
+                        diagram,
+                        &node_handler.get_child(diagram, BinaryIndex::Left).unwrap(),
+                        inverse_table,
+                        f,
+                        index,
+                        visit_record,
+                    )?;
+                }
+                if right_recursive_flag {
+                    Self::fmt_reclusive(
+                        diagram,
+                        &node_handler.get_child(diagram, BinaryIndex::Right).unwrap(),
+                        inverse_table,
+                        f,
+                        index,
+                        visit_record,
+                    )?;
+                }
+                Ok(())
+            }
+            Element::Binary(_) => Ok(()),
+        }
+    }
+}
+
+impl<T> Display for FormulaRoot<T>
+where
+    T: Display,
+{
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        self.generic_fmt(f)
+    }
+}
+
+#[cfg(test)]
+fn names(names: &[&str]) -> Vec<String> {
+    names.iter().map(|name| name.to_string()).collect()
+}
+
+// The values of the named variables, as a cube or an assignment
+#[cfg(test)]
+fn values<C>(values: &[(&str, bool)]) -> C
+where
+    C: FromIterator<(String, bool)>,
+{
+    values
+        .iter()
+        .map(|(name, value)| (name.to_string(), *value))
+        .collect()
+}
+
+#[test]
+fn formula_sat_count_test() {
+    let manager = Manager::new();
+    let f = manager.add_formula("(a -> b) & (b -> c)").unwrap();
+    assert_eq!(f.sat_count(names(&["a", "b", "c"])).to_u64(), Some(4));
+    assert_eq!(f.sat_count(names(&["c", "b", "a", "d"])).to_string(), "8");
+    let wide: Vec<String> = (0..70).map(|i| format!("x{i}")).collect();
+    let f = manager.add_formula(&wide.join(" | ")).unwrap();
+    assert_eq!(f.sat_count(wide).to_string(), "1180591620717411303423");
+}
+
+#[test]
+fn formula_witness_test() {
+    let manager = Manager::new();
+    let f = manager
+        .add_formula("(a | b) & (b -> c) & (a -> !c)")
+        .unwrap();
+    assert_eq!(
+        f.min_sat(),
+        Some(values(&[("a", false), ("b", true), ("c", true)]))
+    );
+    assert_eq!(
+        f.max_sat(),
+        Some(values(&[("a", true), ("b", false), ("c", false)]))
+    );
+    let witness: HashMap<String, bool> = f.any_sat().unwrap().into_iter().collect();
+    assert!(manager.restrict(&f, &witness) == manager.constant(true));
+    assert_eq!((&f & &!&f).any_sat(), None);
+}
+
+#[test]
+fn formula_eval_test() {
+    let manager = Manager::new();
+    let f = manager.add_formula("a -> b & c").unwrap();
+    assert_eq!(f.eval(&values(&[("a", false)])), Ok(true));
+    assert_eq!(
+        f.eval(&values(&[("a", true), ("b", true)])),
+        Err(MissingVariable("c".to_string()))
+    );
+    // All 8 assignments, twice over to span two batches of 64 lanes
+    let assignments: Vec<HashMap<String, bool>> = (0..100)
+        .map(|i| values(&[("a", i & 4 != 0), ("b", i & 2 != 0), ("c", i & 1 != 0)]))
+        .collect();
+    let results = f.eval_batch(&assignments).unwrap();
+    for (assignment, value) in assignments.iter().zip(results) {
+        assert_eq!(f.eval(assignment), Ok(value));
+    }
+    assert_eq!(
+        f.eval_batch(&[values(&[("a", false), ("b", false)])])
+            .unwrap_err()
+            .to_string(),
+        "No value for the variable c"
+    );
+}
+
+#[test]
+fn dependence_test() {
+    let manager = Manager::new();
+    let f = manager.add_formula("a | !a & b | a & c").unwrap();
+    assert_eq!(f.support(), names(&["a", "b"]));
+    assert_eq!(
+        f.removable_variables(names(&["a", "b", "c"])),
+        names(&["c"])
+    );
+    assert_eq!(f.essential_variables(), vec![]);
+    let g = manager.add_formula("a & (b -> c) & !d").unwrap();
+    assert_eq!(
+        g.essential_variables(),
+        vec![("a".to_string(), true), ("d".to_string(), false)]
+    );
+}
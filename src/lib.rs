@@ -1,19 +1,24 @@
 //! This project aims to generate a Reduced Ordered Binary Decision Diagram from a text-based PL formula.
 
+mod assignments;
+mod big_unsigned;
 mod binary_decision_diagram;
+mod formula_root;
+mod manager;
+mod ordering_heuristic;
 mod utility;
-use std::{cmp::Ordering, collections::HashMap, hash::Hash};
+use std::{collections::HashMap, hash::Hash};
 mod formula_parser;
 use binary_decision_diagram::*;
 use formula_parser::ParserNode;
 
-#[derive(Clone, Copy)]
+#[derive(Clone, Copy, Debug, Hash, PartialEq, Eq)]
 pub enum Operation {
     Binary(BinaryOperation),
     Unary(UnaryOperation),
 }
 
-#[derive(Clone, Copy, Debug)]
+#[derive(Clone, Copy, Debug, Hash, PartialEq, Eq)]
 pub enum BinaryOperation {
     And,
     Or,
@@ -21,63 +26,283 @@ pub enum BinaryOperation {
     Equivalence,
 }
 
-#[derive(Clone, Copy, Debug)]
+#[derive(Clone, Copy, Debug, Hash, PartialEq, Eq)]
 pub enum UnaryOperation {
     Not,
 }
 
+#[derive(Clone, Copy, Debug, Hash, PartialEq, Eq)]
+pub enum Quantifier {
+    Exists,
+    Forall,
+}
+
 pub type LexerError<'a> =
     lalrpop_util::ParseError<usize, lalrpop_util::lexer::Token<'a>, &'static str>;
-pub use binary_decision_diagram::node_handler::FormulaRoot;
+pub use assignments::{Cubes, Minterms};
+pub use big_unsigned::BigUnsigned;
+pub use binary_decision_diagram::{CacheStatistics, MAXIMUM_EXACT_ORDERING_VARIABLES};
+pub use formula_root::{FormulaRoot, MissingVariable};
+pub use manager::{Manager, Verdict};
+pub use ordering_heuristic::{OrderingHeuristic, OrderingReport};
+
+pub fn construct_robdd(input: &str) -> Result<FormulaRoot<String>, LexerError<'_>> {
+    Manager::new().add_formula(input)
+}
 
-pub fn construct_robdd<'a>(
+/// Like `construct_robdd`, with the variables of `order` placed first, in that order, and the
+/// others after them in order of appearance. The order can also be declared at the beginning
+/// of `input`, e.g. `order: a < b < c`.
+pub fn construct_robdd_with_order<'a>(
     input: &'a str,
-) -> Result<(BinaryDecisionDiagram<usize>, FormulaRoot<String>), LexerError> {
-    let mut diagram = BinaryDecisionDiagram::default();
-    let mut inverse_table = vec![];
-    let root = construct_robdd_from_parser_tree(
-        &rename_variable(
-            &formula_parser::formula_parse(input)?,
-            &mut HashMap::default(),
-            &mut inverse_table,
-        ),
-        &mut diagram,
-    );
-    Ok((
-        diagram,
-        FormulaRoot::new(root, inverse_table.into_iter().enumerate().collect()),
-    ))
+    order: &[&str],
+) -> Result<FormulaRoot<String>, LexerError<'a>> {
+    let manager = Manager::new();
+    manager.declare_order(order.iter().map(|name| name.to_string()));
+    manager.add_formula(input)
+}
+
+/// Like `construct_robdd`, with the variable order chosen by `heuristic` from the structure
+/// of the formula. Variables in an order declaration at the beginning of `input` still come
+/// first. Also returns which heuristic was used and the resulting number of nodes.
+pub fn construct_robdd_with_heuristic(
+    input: &str,
+    heuristic: OrderingHeuristic,
+) -> Result<(FormulaRoot<String>, OrderingReport<String>), LexerError<'_>> {
+    let (declared_order, parser_tree) = formula_parser::input_parse(input)?;
+    let build = |heuristic: OrderingHeuristic| {
+        let manager = Manager::new();
+        manager.declare_order(declared_order.iter().cloned());
+        manager.declare_order(heuristic.order(&parser_tree));
+        let root = manager.add_parser_tree(&parser_tree);
+        let report = OrderingReport {
+            heuristic,
+            order: manager.0.borrow().inverse_table.clone(),
+            node_count: root.node_count(),
+        };
+        (root, report)
+    };
+    Ok(heuristic
+        .candidates()
+        .into_iter()
+        .map(build)
+        .min_by_key(|(_, report)| report.node_count)
+        .unwrap())
+}
+
+/// The variables of `input` which only occur bound by a quantifier, like `x` in
+/// `exists x. x & y`, in order of appearance. The formula cannot depend on them.
+pub fn bound_variables(input: &str) -> Result<Vec<String>, LexerError<'_>> {
+    let (_, parser_tree) = formula_parser::input_parse(input)?;
+    let (mut free, mut bound) = (vec![], vec![]);
+    collect_variables(&parser_tree, &mut vec![], &mut free, &mut bound);
+    Ok(bound
+        .into_iter()
+        .filter(|name| !free.contains(name))
+        .collect())
+}
+
+// Sorts the variables of `input` into the free and the bound ones, `scope` holding the
+// variables bound where `input` is
+fn collect_variables<T>(
+    input: &ParserNode<T>,
+    scope: &mut Vec<T>,
+    free: &mut Vec<T>,
+    bound: &mut Vec<T>,
+) where
+    T: Eq + Clone,
+{
+    match input {
+        ParserNode::Unary(_, operand) => collect_variables(operand, scope, free, bound),
+        ParserNode::Binary(_, (left, right)) => {
+            collect_variables(left, scope, free, bound);
+            collect_variables(right, scope, free, bound);
+        }
+        ParserNode::Variable(var) => {
+            if !scope.contains(var) && !free.contains(var) {
+                free.push(var.clone());
+            }
+        }
+        ParserNode::Leaf(_) => {}
+        ParserNode::Quantified(_, variables, operand) => {
+            for var in variables {
+                if !bound.contains(var) {
+                    bound.push(var.clone());
+                }
+            }
+            let depth = scope.len();
+            scope.extend(variables.iter().cloned());
+            collect_variables(operand, scope, free, bound);
+            scope.truncate(depth);
+        }
+    }
 }
 
+// The returned node is referenced; the caller is responsible for dereferencing it
 fn construct_robdd_from_parser_tree(
     input: &ParserNode<usize>,
     diagram: &mut BinaryDecisionDiagram<usize>,
-) -> NodeHandler<usize> {
-    match input {
+) -> NodeHandler {
+    let result = match input {
         ParserNode::Unary(op, operand) => match op {
             UnaryOperation::Not => {
                 let operand = construct_robdd_from_parser_tree(operand, diagram);
-                apply_unary(diagram, operand, *op)
+                let result = apply_unary(diagram, operand, *op);
+                diagram.reference(&result);
+                diagram.dereference(&operand);
+                result
             }
         },
         ParserNode::Binary(op, (left, right)) => {
             let left = construct_robdd_from_parser_tree(left, diagram);
             let right = construct_robdd_from_parser_tree(right, diagram);
-            apply_binary(diagram, (left, right), *op)
+            let result = apply_binary(diagram, (left, right), *op);
+            diagram.reference(&result);
+            diagram.dereference(&left);
+            diagram.dereference(&right);
+            result
         }
-        ParserNode::Variable(var) => diagram.add_variable(*var),
-        ParserNode::Leaf(value) => BinaryDecisionDiagram::get_leaf(*value),
-    }
+        ParserNode::Variable(var) => {
+            let result = diagram.add_variable(*var);
+            diagram.reference(&result);
+            result
+        }
+        ParserNode::Leaf(value) => BinaryDecisionDiagram::<usize>::get_leaf(*value),
+        ParserNode::Quantified(quantifier, variables, operand) => {
+            let operand = construct_robdd_from_parser_tree(operand, diagram);
+            let result = match quantifier {
+                Quantifier::Exists => diagram.exists(operand, variables),
+                Quantifier::Forall => diagram.forall(operand, variables),
+            };
+            diagram.reference(&result);
+            diagram.dereference(&operand);
+            result
+        }
+    };
+    diagram.collect_garbage_if_necessary();
+    diagram.reorder_if_necessary();
+    result
 }
 
 #[test]
 fn construct_test() {
     println!(
         "{}",
-        construct_robdd("(!x1 | x2) & (x1 | !x3) & (!x1 | !x2 | x3)")
-            .unwrap()
-            .1
+        construct_robdd("(!x1 | x2) & (x1 | !x3) & (!x1 | !x2 | x3)").unwrap()
+    );
+}
+
+#[test]
+fn formula_garbage_collection_test() {
+    let root = construct_robdd("(a | b) & !(a & b) | c").unwrap();
+    let manager = root.manager().clone();
+    manager.collect_garbage();
+    let node_count = manager.node_count();
+    assert!(node_count > 0);
+    let copy = root.clone();
+    drop(root);
+    assert_eq!(manager.collect_garbage(), 0);
+    drop(copy);
+    assert_eq!(manager.collect_garbage(), node_count);
+}
+
+#[test]
+fn complement_edge_test() {
+    let mut diagram = BinaryDecisionDiagram::default();
+    let (a, b) = (diagram.add_variable(0), diagram.add_variable(1));
+    let f = apply_binary(&mut diagram, (a, b), BinaryOperation::Equivalence);
+    let node_count = diagram.node_count();
+    let not_f = apply_unary(&mut diagram, f, UnaryOperation::Not);
+    assert_eq!(diagram.node_count(), node_count);
+    assert_eq!(apply_unary(&mut diagram, not_f, UnaryOperation::Not), f);
+    assert_eq!(
+        apply_binary(&mut diagram, (a.negate(), b), BinaryOperation::Equivalence),
+        not_f
+    );
+    assert_eq!(
+        apply_binary(&mut diagram, (f, not_f), BinaryOperation::Or),
+        BinaryDecisionDiagram::<usize>::get_leaf(true)
+    );
+}
+
+#[test]
+fn variable_order_test() {
+    let formula = "x1 & x2 | x3 & x4 | x5 & x6";
+    let node_count = |root: FormulaRoot<String>| {
+        root.manager().collect_garbage();
+        root.manager().node_count()
+    };
+    let interleaved = node_count(construct_robdd(formula).unwrap());
+    let separated = node_count(
+        construct_robdd_with_order(formula, &["x1", "x3", "x5", "x2", "x4", "x6"]).unwrap(),
     );
+    assert!(interleaved < separated);
+    let declared =
+        node_count(construct_robdd(&format!("order: x1 < x3 < x5 < x2\n{formula}")).unwrap());
+    assert_eq!(declared, separated);
+}
+
+#[test]
+fn construct_with_heuristic_test() {
+    let formula = "(x1 | x2 | x3) & (x1 <-> y1) & (x2 <-> y2) & (x3 <-> y3)";
+    let node_counts: Vec<usize> = [
+        OrderingHeuristic::Appearance,
+        OrderingHeuristic::DepthFirst,
+        OrderingHeuristic::WeightedFanIn,
+        OrderingHeuristic::Force,
+    ]
+    .into_iter()
+    .map(|heuristic| {
+        let (root, report) = construct_robdd_with_heuristic(formula, heuristic).unwrap();
+        assert_eq!(report.heuristic, heuristic);
+        assert_eq!(report.node_count, root.node_count());
+        report.node_count
+    })
+    .collect();
+    let (_, best) = construct_robdd_with_heuristic(formula, OrderingHeuristic::Best).unwrap();
+    assert_eq!(best.node_count, *node_counts.iter().min().unwrap());
+    assert!(best.node_count < node_counts[0]);
+}
+
+#[test]
+fn quantifier_test() {
+    let manager = Manager::new();
+    let f = manager.parse("x & y | !x & z");
+    assert!(manager.parse("exists x. x & y | !x & z") == manager.parse("y | z"));
+    assert!(manager.exists(&f, ["x".to_string()]) == manager.parse("y | z"));
+    assert!(manager.forall(&f, ["x".to_string()]) == manager.parse("y & z"));
+    assert!(manager.parse("forall x. exists y. x <-> y") == manager.constant(true));
+    assert!(manager.parse("exists x, y. (x & y -> z)") == manager.constant(true));
+    assert!(manager.parse("(exists y. x & y) & forall x. x | y") == manager.parse("x & y"));
+    assert!(manager.parse("y & !exists x. x & y") == manager.constant(false));
+}
+
+#[test]
+fn bound_variables_test() {
+    assert_eq!(bound_variables("exists x. x & y").unwrap(), ["x"]);
+    assert_eq!(
+        bound_variables("forall x, z. exists x. x -> y").unwrap(),
+        ["x", "z"]
+    );
+    // Each of x and y also occurs free
+    assert!(bound_variables("(exists y. x & y) & forall x. x | y")
+        .unwrap()
+        .is_empty());
+}
+
+#[test]
+fn computed_table_test() {
+    let formula = (1..=20)
+        .map(|i| format!("x{} & x{}", 2 * i - 1, 2 * i))
+        .collect::<Vec<_>>()
+        .join(" | ");
+    let root = construct_robdd(&formula).unwrap();
+    // One node per variable, built without the exponential blowup of recomputing shared
+    // subproblems
+    assert_eq!(root.node_count(), 40);
+    let statistics = root.manager().cache_statistics();
+    assert!(statistics.hits > 0);
+    assert!(statistics.misses < 1000);
 }
 
 fn rename_variable<From>(
@@ -101,129 +326,64 @@ where
             ),
         ),
         ParserNode::Variable(var) => {
-            if !symbol_table.contains_key(var) {
-                symbol_table.insert(var.clone(), inverse_table.len());
-                inverse_table.push(var.clone());
-            }
-            ParserNode::Variable(symbol_table[var])
+            ParserNode::Variable(variable_index(var, symbol_table, inverse_table))
         }
         ParserNode::Leaf(value) => ParserNode::Leaf(*value),
+        ParserNode::Quantified(quantifier, variables, operand) => ParserNode::Quantified(
+            *quantifier,
+            variables
+                .iter()
+                .map(|var| variable_index(var, symbol_table, inverse_table))
+                .collect(),
+            Box::new(rename_variable(operand, symbol_table, inverse_table)),
+        ),
     }
 }
 
+// The number of `var`, which is numbered after the known variables if it is new
+fn variable_index<From>(
+    var: &From,
+    symbol_table: &mut HashMap<From, usize>,
+    inverse_table: &mut Vec<From>,
+) -> usize
+where
+    From: Eq + Hash + Clone,
+{
+    if !symbol_table.contains_key(var) {
+        symbol_table.insert(var.clone(), inverse_table.len());
+        inverse_table.push(var.clone());
+    }
+    symbol_table[var]
+}
+
 fn apply_binary<T>(
     diagram: &mut BinaryDecisionDiagram<T>,
-    operands: (NodeHandler<T>, NodeHandler<T>),
+    operands: (NodeHandler, NodeHandler),
     operation: BinaryOperation,
-) -> NodeHandler<T>
+) -> NodeHandler
 where
-    T: Clone + Eq + Ord + Copy,
+    T: Clone + Eq + Ord + Hash,
 {
-    let elements = (operands.0.get_element(), operands.1.get_element());
-
-    // Basic case
-    if let Element::Binary(value) = elements.0 {
-        match value {
-            true => match operation {
-                BinaryOperation::And => return operands.1,
-                BinaryOperation::Or => return BinaryDecisionDiagram::get_leaf(true),
-                BinaryOperation::Implication => return operands.1,
-                BinaryOperation::Equivalence => return operands.1,
-            },
-            false => match operation {
-                BinaryOperation::And => return BinaryDecisionDiagram::get_leaf(false),
-                BinaryOperation::Or => return operands.1,
-                BinaryOperation::Implication => return BinaryDecisionDiagram::get_leaf(true),
-                BinaryOperation::Equivalence => {
-                    return apply_unary(diagram, operands.1, UnaryOperation::Not)
-                }
-            },
-        }
-    }
-    if let Element::Binary(value) = elements.1 {
-        match value {
-            true => match operation {
-                BinaryOperation::And => return operands.0,
-                BinaryOperation::Or => return BinaryDecisionDiagram::get_leaf(true),
-                BinaryOperation::Implication => return BinaryDecisionDiagram::get_leaf(true),
-                BinaryOperation::Equivalence => return operands.0,
-            },
-            false => match operation {
-                BinaryOperation::And => return BinaryDecisionDiagram::get_leaf(false),
-                BinaryOperation::Or => return operands.0,
-                BinaryOperation::Implication => {
-                    return apply_unary(diagram, operands.0, UnaryOperation::Not)
-                }
-                BinaryOperation::Equivalence => {
-                    return apply_unary(diagram, operands.0, UnaryOperation::Not)
-                }
-            },
-        }
-    }
-
-    // Case 1: both have smallest variable
-    if Ord::cmp(&elements.0, &elements.1) == Ordering::Equal {
-        let (t1, t2, u1, u2) = (
-            operands.0.get_child(BinaryIndex::Left).unwrap(),
-            operands.0.get_child(BinaryIndex::Right).unwrap(),
-            operands.1.get_child(BinaryIndex::Left).unwrap(),
-            operands.1.get_child(BinaryIndex::Right).unwrap(),
-        );
-        let new_children = (
-            apply_binary(diagram, (t1, u1), operation),
-            apply_binary(diagram, (t2, u2), operation),
-        );
-        return diagram
-            .add_node_if_necessary(unwrap!(elements.0, Element::Variable(v), *v), new_children);
+    let (f, g) = operands;
+    let (false_leaf, true_leaf) = BinaryDecisionDiagram::<T>::get_leaves();
+    match operation {
+        BinaryOperation::And => diagram.ite(f, g, false_leaf),
+        BinaryOperation::Or => diagram.ite(f, true_leaf, g),
+        BinaryOperation::Implication => diagram.ite(f, g, true_leaf),
+        BinaryOperation::Equivalence => diagram.ite(f, g, g.negate()),
     }
-
-    // Case 2: the smallest variable only appears on one side
-    let (smaller, larger) = match Ord::cmp(&elements.0, &elements.1) {
-        Ordering::Less => (operands.0, operands.1),
-        Ordering::Greater => (operands.1, operands.0),
-        _ => panic!(),
-    };
-
-    let (t1, t2, u) = (
-        smaller.get_child(BinaryIndex::Left).unwrap(),
-        smaller.get_child(BinaryIndex::Right).unwrap(),
-        larger,
-    );
-    let new_children = (
-        apply_binary(diagram, (t1, u), operation),
-        apply_binary(diagram, (t2, u), operation),
-    );
-
-    return diagram.add_node_if_necessary(
-        unwrap!(smaller.get_element(), Element::Variable(v), *v),
-        new_children,
-    );
 }
+
 fn apply_unary<T>(
     diagram: &mut BinaryDecisionDiagram<T>,
-    operand: NodeHandler<T>,
+    operand: NodeHandler,
     operation: UnaryOperation,
-) -> NodeHandler<T>
+) -> NodeHandler
 where
-    T: Clone + Eq + Ord,
+    T: Clone + Eq + Ord + Hash,
 {
+    let (false_leaf, true_leaf) = BinaryDecisionDiagram::<T>::get_leaves();
     match operation {
-        UnaryOperation::Not => match operand.get_element() {
-            Element::Variable(var) => {
-                let children = (
-                    operand.get_child(BinaryIndex::Left).unwrap(),
-                    operand.get_child(BinaryIndex::Right).unwrap(),
-                );
-                let not_children = (
-                    apply_unary(diagram, children.0, operation),
-                    apply_unary(diagram, children.1, operation),
-                );
-                diagram.add_node_if_necessary(var.clone(), not_children)
-            }
-            Element::Binary(value) => match value {
-                true => BinaryDecisionDiagram::<T>::get_leaf(false),
-                false => BinaryDecisionDiagram::<T>::get_leaf(true),
-            },
-        },
+        UnaryOperation::Not => diagram.ite(operand, false_leaf, true_leaf),
     }
 }
@@ -0,0 +1,698 @@
+use std::{cell::RefCell, collections::HashMap, hash::Hash, rc::Rc};
+
+use crate::{
+    apply_binary, apply_unary,
+    binary_decision_diagram::*,
+    construct_robdd_from_parser_tree,
+    formula_parser::{self, ParserNode},
+    rename_variable, BinaryOperation, FormulaRoot, LexerError, UnaryOperation,
+};
+
+pub(crate) struct Context<T> {
+    pub(crate) diagram: BinaryDecisionDiagram<usize>,
+
+    // Variables are numbered in the diagram; these map their names to the numbers and back
+    pub(crate) symbol_table: HashMap<T, usize>,
+    pub(crate) inverse_table: Vec<T>,
+}
+
+impl<T> Context<T>
+where
+    T: Eq + Hash + Clone,
+{
+    // The number of the variable `name`, which is numbered after the known ones if it is new
+    pub(crate) fn index_of(&mut self, name: T) -> usize {
+        let inverse_table = &mut self.inverse_table;
+        *self.symbol_table.entry(name.clone()).or_insert_with(|| {
+            inverse_table.push(name);
+            inverse_table.len() - 1
+        })
+    }
+
+    // The values of the known variables of `values` by number. No formula depends on the
+    // others.
+    pub(crate) fn by_index<V: Copy>(&self, values: &HashMap<T, V>) -> HashMap<usize, V> {
+        values
+            .iter()
+            .filter_map(|(name, value)| Some((*self.symbol_table.get(name)?, *value)))
+            .collect()
+    }
+}
+
+/// The answer to a query about formulas, with a counterexample when it is no: a partial
+/// assignment under which the property fails whatever the values of the other variables.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum Verdict<T> {
+    Holds,
+    Counterexample(Vec<(T, bool)>),
+}
+
+impl<T> Verdict<T> {
+    pub fn holds(&self) -> bool {
+        matches!(self, Verdict::Holds)
+    }
+}
+
+/// Builds formulas over variables named by `T` in one shared diagram.
+///
+/// Cloning a `Manager` gives another handle to the same diagram. Every formula it builds
+/// keeps the diagram alive, and formulas may only be combined with those of the same manager.
+pub struct Manager<T>(pub(crate) Rc<RefCell<Context<T>>>);
+
+impl<T> Clone for Manager<T> {
+    fn clone(&self) -> Self {
+        Manager(self.0.clone())
+    }
+}
+
+impl<T> Default for Manager<T> {
+    fn default() -> Self {
+        Manager(Rc::new(RefCell::new(Context {
+            diagram: BinaryDecisionDiagram::default(),
+            symbol_table: HashMap::default(),
+            inverse_table: Vec::default(),
+        })))
+    }
+}
+
+// For basic operations
+impl<T> Manager<T>
+where
+    T: Eq + Hash + Clone,
+{
+    // Wraps a node of the diagram into a formula. As every other formula in use is referenced,
+    // this is also where garbage gets collected and variables get reordered.
+    pub(crate) fn root(&self, node_handler: NodeHandler) -> FormulaRoot<T> {
+        let root = FormulaRoot::new(node_handler, self.clone());
+        let diagram = &mut self.0.borrow_mut().diagram;
+        diagram.collect_garbage_if_necessary();
+        diagram.reorder_if_necessary();
+        root
+    }
+
+    fn node_handler(&self, formula: &FormulaRoot<T>) -> NodeHandler {
+        assert!(
+            Rc::ptr_eq(&self.0, &formula.manager().0),
+            "The formula belongs to another manager"
+        );
+        formula.node_handler()
+    }
+
+    pub(crate) fn add_parser_tree(&self, parser_tree: &ParserNode<T>) -> FormulaRoot<T> {
+        let root = {
+            let mut context = self.0.borrow_mut();
+            let context = &mut *context;
+            construct_robdd_from_parser_tree(
+                &rename_variable(
+                    parser_tree,
+                    &mut context.symbol_table,
+                    &mut context.inverse_table,
+                ),
+                &mut context.diagram,
+            )
+        };
+        let formula_root = self.root(root);
+        self.0.borrow_mut().diagram.dereference(&root);
+        formula_root
+    }
+
+    // The numbers of the variables named in `variables`. The unknown names are left out, as
+    // no formula depends on them.
+    fn variable_indices(&self, variables: impl IntoIterator<Item = T>) -> Vec<usize> {
+        let context = self.0.borrow();
+        variables
+            .into_iter()
+            .filter_map(|name| context.symbol_table.get(&name).copied())
+            .collect()
+    }
+
+    fn index_mapping(&self, mapping: &HashMap<T, T>) -> HashMap<usize, usize> {
+        let mut context = self.0.borrow_mut();
+        mapping
+            .iter()
+            .map(|(from, to)| (context.index_of(from.clone()), context.index_of(to.clone())))
+            .collect()
+    }
+
+    fn apply_binary(
+        &self,
+        operands: (&FormulaRoot<T>, &FormulaRoot<T>),
+        operation: BinaryOperation,
+    ) -> FormulaRoot<T> {
+        let operands = (self.node_handler(operands.0), self.node_handler(operands.1));
+        let result = apply_binary(&mut self.0.borrow_mut().diagram, operands, operation);
+        self.root(result)
+    }
+}
+
+// For API implementation
+impl<T> Manager<T>
+where
+    T: Eq + Hash + Clone,
+{
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// The formula consisting of the variable `name` alone. Variables are ordered by
+    /// their first use in the manager.
+    pub fn var(&self, name: T) -> FormulaRoot<T> {
+        let node_handler = {
+            let mut context = self.0.borrow_mut();
+            let index = context.index_of(name);
+            context.diagram.add_variable(index)
+        };
+        self.root(node_handler)
+    }
+
+    /// Places the variables not used in the manager yet in the given order, closer to the root
+    /// than the ones introduced later. Variables already in the manager keep their place.
+    pub fn declare_order(&self, order: impl IntoIterator<Item = T>) {
+        let mut context = self.0.borrow_mut();
+        for name in order {
+            context.index_of(name);
+        }
+    }
+
+    pub fn constant(&self, value: bool) -> FormulaRoot<T> {
+        self.root(BinaryDecisionDiagram::<usize>::get_leaf(value))
+    }
+
+    pub fn not(&self, f: &FormulaRoot<T>) -> FormulaRoot<T> {
+        let operand = self.node_handler(f);
+        let result = apply_unary(
+            &mut self.0.borrow_mut().diagram,
+            operand,
+            UnaryOperation::Not,
+        );
+        self.root(result)
+    }
+
+    pub fn and(&self, f: &FormulaRoot<T>, g: &FormulaRoot<T>) -> FormulaRoot<T> {
+        self.apply_binary((f, g), BinaryOperation::And)
+    }
+
+    pub fn or(&self, f: &FormulaRoot<T>, g: &FormulaRoot<T>) -> FormulaRoot<T> {
+        self.apply_binary((f, g), BinaryOperation::Or)
+    }
+
+    pub fn implies(&self, f: &FormulaRoot<T>, g: &FormulaRoot<T>) -> FormulaRoot<T> {
+        self.apply_binary((f, g), BinaryOperation::Implication)
+    }
+
+    pub fn iff(&self, f: &FormulaRoot<T>, g: &FormulaRoot<T>) -> FormulaRoot<T> {
+        self.apply_binary((f, g), BinaryOperation::Equivalence)
+    }
+
+    pub fn xor(&self, f: &FormulaRoot<T>, g: &FormulaRoot<T>) -> FormulaRoot<T> {
+        let (f, g) = (self.node_handler(f), self.node_handler(g));
+        let result = self.0.borrow_mut().diagram.ite(f, g.negate(), g);
+        self.root(result)
+    }
+
+    /// `g` if `f` holds and `h` otherwise.
+    pub fn ite(
+        &self,
+        f: &FormulaRoot<T>,
+        g: &FormulaRoot<T>,
+        h: &FormulaRoot<T>,
+    ) -> FormulaRoot<T> {
+        let (f, g, h) = (
+            self.node_handler(f),
+            self.node_handler(g),
+            self.node_handler(h),
+        );
+        let result = self.0.borrow_mut().diagram.ite(f, g, h);
+        self.root(result)
+    }
+
+    /// The variables from the root down.
+    pub fn variable_order(&self) -> Vec<T> {
+        let context = self.0.borrow();
+        context
+            .diagram
+            .variable_order()
+            .iter()
+            .map(|index| context.inverse_table[*index].clone())
+            .collect()
+    }
+
+    /// Reorders the variables by sifting to reduce the number of nodes, which the formulas
+    /// of the manager keep denoting the same functions. Returns the number of nodes afterwards.
+    pub fn reorder(&self) -> usize {
+        self.0.borrow_mut().diagram.sift()
+    }
+
+    /// Reorders the variables by window permutation, trying every order of `window` adjacent
+    /// variables or groups, from 2 to 4, at a time. Returns the number of nodes afterwards.
+    pub fn reorder_by_window_permutation(&self, window: usize) -> usize {
+        self.0.borrow_mut().diagram.window_permutation(window)
+    }
+
+    /// Exchanges the variables at `level` and `level + 1`, 0 being the top, which the
+    /// formulas of the manager keep denoting the same functions through. Panics if either
+    /// variable belongs to a group.
+    pub fn swap_adjacent_levels(&self, level: usize) {
+        self.0.borrow_mut().diagram.swap_adjacent_levels(level);
+    }
+
+    /// Keeps `variables` together, in this order, whenever the variables are reordered, like
+    /// the bits of a number or a pair of current and next state variables. They are first
+    /// moved right below the topmost of them. Panics if one of them appears twice or is
+    /// already in a group.
+    pub fn group(&self, variables: impl IntoIterator<Item = T>) {
+        let mut context = self.0.borrow_mut();
+        let variables = variables
+            .into_iter()
+            .map(|name| context.index_of(name))
+            .collect();
+        context.diagram.add_group(variables);
+    }
+
+    /// Reorders the variables automatically whenever the number of nodes exceeds `threshold`,
+    /// which is raised as needed. `None`, the default, disables automatic reordering.
+    pub fn set_reordering_threshold(&self, threshold: Option<usize>) {
+        self.0
+            .borrow_mut()
+            .diagram
+            .set_reordering_threshold(threshold);
+    }
+
+    /// An order of the variables `f` depends on for which `f` has the fewest nodes, and that
+    /// number of nodes. Its cost is exponential in the number of variables, so it panics if
+    /// `f` depends on more than `MAXIMUM_EXACT_ORDERING_VARIABLES`, i.e. 16, of them.
+    pub fn optimal_order(&self, f: &FormulaRoot<T>) -> (Vec<T>, usize) {
+        let root = self.node_handler(f);
+        let mut context = self.0.borrow_mut();
+        let (order, size) = context.diagram.optimal_order(root);
+        let order = order
+            .into_iter()
+            .map(|index| context.inverse_table[index].clone())
+            .collect();
+        (order, size)
+    }
+
+    /// `f` with `variables` existentially quantified: it holds iff `f` holds for some values
+    /// of `variables`.
+    pub fn exists(
+        &self,
+        f: &FormulaRoot<T>,
+        variables: impl IntoIterator<Item = T>,
+    ) -> FormulaRoot<T> {
+        let (f, variables) = (self.node_handler(f), self.variable_indices(variables));
+        let result = self.0.borrow_mut().diagram.exists(f, &variables);
+        self.root(result)
+    }
+
+    /// `f` with `variables` universally quantified: it holds iff `f` holds for all values
+    /// of `variables`.
+    pub fn forall(
+        &self,
+        f: &FormulaRoot<T>,
+        variables: impl IntoIterator<Item = T>,
+    ) -> FormulaRoot<T> {
+        let (f, variables) = (self.node_handler(f), self.variable_indices(variables));
+        let result = self.0.borrow_mut().diagram.forall(f, &variables);
+        self.root(result)
+    }
+
+    /// `f` with the variables of `assignment` set to their values, like `f|x=1,y=0`.
+    pub fn restrict(&self, f: &FormulaRoot<T>, assignment: &HashMap<T, bool>) -> FormulaRoot<T> {
+        let f = self.node_handler(f);
+        let assignment = self.0.borrow().by_index(assignment);
+        let result = self.0.borrow_mut().diagram.restrict(f, &assignment);
+        self.root(result)
+    }
+
+    /// A formula that agrees with `f` wherever `care` holds, by the generalized cofactor of
+    /// Coudert and Madre. See `BinaryDecisionDiagram::constrain`.
+    pub fn constrain(&self, f: &FormulaRoot<T>, care: &FormulaRoot<T>) -> FormulaRoot<T> {
+        let (f, care) = (self.node_handler(f), self.node_handler(care));
+        let result = self.0.borrow_mut().diagram.constrain(f, care);
+        self.root(result)
+    }
+
+    /// A formula with few nodes that agrees with `f` wherever `care` holds, by the restrict
+    /// operator of Coudert and Madre, named apart from `restrict` by an assignment. See
+    /// `BinaryDecisionDiagram::simplify`.
+    pub fn simplify(&self, f: &FormulaRoot<T>, care: &FormulaRoot<T>) -> FormulaRoot<T> {
+        let (f, care) = (self.node_handler(f), self.node_handler(care));
+        let result = self.0.borrow_mut().diagram.simplify(f, care);
+        self.root(result)
+    }
+
+    /// `f` with the formula `g` in place of the variable `name`.
+    pub fn compose(&self, f: &FormulaRoot<T>, name: T, g: &FormulaRoot<T>) -> FormulaRoot<T> {
+        let (f, g) = (self.node_handler(f), self.node_handler(g));
+        // No formula depends on an unknown variable
+        let Some(index) = self.0.borrow().symbol_table.get(&name).copied() else {
+            return self.root(f);
+        };
+        let result = self.0.borrow_mut().diagram.compose(f, &index, g);
+        self.root(result)
+    }
+
+    /// `f` with each variable of `substitution` replaced by its formula, all at once.
+    pub fn vector_compose(
+        &self,
+        f: &FormulaRoot<T>,
+        substitution: &HashMap<T, FormulaRoot<T>>,
+    ) -> FormulaRoot<T> {
+        let f = self.node_handler(f);
+        let substitution = substitution
+            .iter()
+            .filter_map(|(name, g)| {
+                let index = *self.0.borrow().symbol_table.get(name)?;
+                Some((index, self.node_handler(g)))
+            })
+            .collect();
+        let result = self.0.borrow_mut().diagram.vector_compose(f, &substitution);
+        self.root(result)
+    }
+
+    /// `f` with each variable of `mapping` renamed to the one it maps to, all at once, like
+    /// current state variables to next state ones. The mapping needs not keep the order.
+    pub fn rename(&self, f: &FormulaRoot<T>, mapping: &HashMap<T, T>) -> FormulaRoot<T> {
+        let f = self.node_handler(f);
+        let mapping = self.index_mapping(mapping);
+        let result = self.0.borrow_mut().diagram.rename(f, &mapping);
+        self.root(result)
+    }
+
+    /// `f` with its variables permuted by `permutation`, which must map its keys one to one
+    /// onto themselves, like swapping the current and next state variables.
+    pub fn permute(&self, f: &FormulaRoot<T>, permutation: &HashMap<T, T>) -> FormulaRoot<T> {
+        let f = self.node_handler(f);
+        let permutation = self.index_mapping(permutation);
+        let result = self.0.borrow_mut().diagram.permute(f, &permutation);
+        self.root(result)
+    }
+
+    /// `exists variables. f & g`, computed without building `f & g`. With `f` a transition
+    /// relation and `g` a set of states, it gives the image of the states.
+    pub fn and_exists(
+        &self,
+        f: &FormulaRoot<T>,
+        g: &FormulaRoot<T>,
+        variables: impl IntoIterator<Item = T>,
+    ) -> FormulaRoot<T> {
+        let (f, g) = (self.node_handler(f), self.node_handler(g));
+        let variables = self.variable_indices(variables);
+        let result = self.0.borrow_mut().diagram.and_exists(f, g, &variables);
+        self.root(result)
+    }
+
+    /// Whether `f` and `g` are equivalent, which only takes comparing their nodes, as equal
+    /// functions share their node. Otherwise gives an assignment on which they differ.
+    pub fn equivalent(&self, f: &FormulaRoot<T>, g: &FormulaRoot<T>) -> Verdict<T> {
+        let (f, g) = (self.node_handler(f), self.node_handler(g));
+        if f == g {
+            return Verdict::Holds;
+        }
+        // Their exclusive or, to find the counterexample
+        let violation = self.0.borrow_mut().diagram.ite(f, g.negate(), g);
+        self.verdict(violation)
+    }
+
+    /// Whether `g` holds whenever `f` does. Otherwise gives an assignment satisfying `f` but
+    /// not `g`.
+    pub fn entails(&self, f: &FormulaRoot<T>, g: &FormulaRoot<T>) -> Verdict<T> {
+        let (f, g) = (self.node_handler(f), self.node_handler(g));
+        let false_leaf = BinaryDecisionDiagram::<usize>::get_leaf(false);
+        let violation = self.0.borrow_mut().diagram.ite(f, g.negate(), false_leaf);
+        self.verdict(violation)
+    }
+
+    /// Whether `f` always holds. Otherwise gives an assignment falsifying it.
+    pub fn is_tautology(&self, f: &FormulaRoot<T>) -> Verdict<T> {
+        self.verdict(self.node_handler(f).negate())
+    }
+
+    /// Whether `f` holds for some assignment. Otherwise the counterexample is empty, as `f`
+    /// fails whatever the values of the variables. See `FormulaRoot::any_sat` for a model.
+    pub fn is_satisfiable(&self, f: &FormulaRoot<T>) -> Verdict<T> {
+        match self.node_handler(f) == BinaryDecisionDiagram::<usize>::get_leaf(false) {
+            true => Verdict::Counterexample(vec![]),
+            false => Verdict::Holds,
+        }
+    }
+
+    // The property holds iff `violation`, which tells where it fails, is false
+    fn verdict(&self, violation: NodeHandler) -> Verdict<T> {
+        let context = self.0.borrow();
+        match context.diagram.any_sat(violation) {
+            None => Verdict::Holds,
+            Some(cube) => Verdict::Counterexample(
+                cube.into_iter()
+                    .map(|(variable, value)| (context.inverse_table[variable].clone(), value))
+                    .collect(),
+            ),
+        }
+    }
+
+    pub fn node_count(&self) -> usize {
+        self.0.borrow().diagram.node_count()
+    }
+
+    pub fn cache_statistics(&self) -> CacheStatistics {
+        self.0.borrow().diagram.cache_statistics()
+    }
+
+    /// Frees the nodes no formula depends on any more. Returns the number of nodes freed.
+    pub fn collect_garbage(&self) -> usize {
+        self.0.borrow_mut().diagram.collect_garbage()
+    }
+
+    /// Collects garbage automatically whenever the number of nodes exceeds `threshold`, which
+    /// is raised as needed. `None` disables automatic garbage collection, leaving it to
+    /// `collect_garbage`.
+    pub fn set_garbage_collection_threshold(&self, threshold: Option<usize>) {
+        self.0
+            .borrow_mut()
+            .diagram
+            .set_garbage_collection_threshold(threshold);
+    }
+}
+
+impl Manager<String> {
+    /// Parses `input` and builds it in the diagram of the manager. Variables with the same
+    /// name as in the formulas added before are the same variables.
+    ///
+    /// The input may start with an order declaration like `order: a < b < c`, which is
+    /// passed to `declare_order`.
+    pub fn add_formula<'a>(&self, input: &'a str) -> Result<FormulaRoot<String>, LexerError<'a>> {
+        let (order, parser_tree) = formula_parser::input_parse(input)?;
+        self.declare_order(order);
+        Ok(self.add_parser_tree(&parser_tree))
+    }
+}
+
+#[cfg(test)]
+impl Manager<String> {
+    // `add_formula` for the inputs of the tests, which are well formed
+    pub(crate) fn parse(&self, input: &str) -> FormulaRoot<String> {
+        self.add_formula(input).unwrap()
+    }
+}
+
+#[test]
+fn manager_test() {
+    let manager = Manager::new();
+    let (a, b) = (manager.var("a"), manager.var("b"));
+    let a_implies_b = manager.implies(&a, &b);
+    assert_eq!((!&a | &a & &b).node_handler(), a_implies_b.node_handler());
+    assert_eq!(
+        (&a ^ &b).node_handler(),
+        manager.not(&manager.iff(&a, &b)).node_handler()
+    );
+    assert_eq!(
+        manager.ite(&a, &b, &manager.constant(false)).node_handler(),
+        (a & b).node_handler()
+    );
+}
+
+#[test]
+fn add_formula_test() {
+    let manager = Manager::new();
+    let (f, g) = (
+        manager.add_formula("a -> b").unwrap(),
+        manager.add_formula("!b -> !a").unwrap(),
+    );
+    assert!(f == g);
+    assert!(&f & &manager.var("a".to_string()) == manager.add_formula("b & a").unwrap());
+    assert!(f != crate::construct_robdd("a -> b").unwrap());
+}
+
+#[test]
+fn reorder_test() {
+    let manager = Manager::new();
+    manager.declare_order(["x1", "x3", "x5", "x2", "x4", "x6"].map(String::from));
+    let f = manager.add_formula("x1 & x2 | x3 & x4 | x5 & x6").unwrap();
+    let g = manager.add_formula("x1 -> x2").unwrap();
+    manager.collect_garbage();
+    let node_count = manager.node_count();
+    assert!(manager.reorder() < node_count);
+    assert_eq!(f.node_count(), 6);
+    assert!(f == manager.add_formula("x5 & x6 | x3 & x4 | x1 & x2").unwrap());
+    assert!(g == manager.add_formula("!x1 | x2").unwrap());
+}
+
+#[test]
+fn swap_adjacent_levels_test() {
+    let manager = Manager::new();
+    let f = manager.parse("a & b | c");
+    manager.swap_adjacent_levels(0);
+    assert_eq!(manager.variable_order(), ["b", "a", "c"]);
+    assert!(f == manager.parse("b & a | c"));
+}
+
+#[test]
+fn manager_restrict_test() {
+    let manager = Manager::new();
+    let f = manager.add_formula("(x -> y) & (y <-> z) | w").unwrap();
+    let assignment = HashMap::from([("x".to_string(), true), ("w".to_string(), false)]);
+    let restricted = manager.restrict(&f, &assignment);
+    assert!(restricted == manager.add_formula("y & z").unwrap());
+    let assignment = HashMap::from([("v".to_string(), true)]);
+    assert!(manager.restrict(&f, &assignment) == f);
+}
+
+#[test]
+fn manager_compose_test() {
+    let manager = Manager::new();
+    let (f, g) = (manager.parse("a & (b | c)"), manager.parse("c -> b"));
+    assert!(manager.compose(&f, "b".to_string(), &g) == manager.parse("a & (c -> b | c)"));
+    let substitution = HashMap::from([
+        ("a".to_string(), manager.parse("b")),
+        ("b".to_string(), manager.parse("a")),
+    ]);
+    assert!(manager.vector_compose(&f, &substitution) == manager.parse("b & (a | c)"));
+}
+
+#[test]
+fn manager_rename_test() {
+    let manager = Manager::new();
+    let relation = manager.parse("(x1 <-> !y) & (y1 <-> x)");
+    let names = |pairs: &[(&str, &str)]| {
+        pairs
+            .iter()
+            .map(|(from, to)| (from.to_string(), to.to_string()))
+            .collect::<HashMap<_, _>>()
+    };
+    let swapped = manager.permute(
+        &relation,
+        &names(&[("x", "x1"), ("x1", "x"), ("y", "y1"), ("y1", "y")]),
+    );
+    assert!(swapped == manager.parse("(x <-> !y1) & (y <-> x1)"));
+    let next = manager.rename(
+        &manager.parse("x & !y"),
+        &names(&[("x", "x1"), ("y", "y1")]),
+    );
+    assert!(next == manager.parse("x1 & !y1"));
+    assert!(manager.rename(&next, &names(&[("x1", "z"), ("y1", "x")])) == manager.parse("z & !x"));
+}
+
+#[test]
+fn simplify_test() {
+    let manager = Manager::new();
+    // Only the inputs with at most one of a, b, c set matter
+    let care = manager.parse("!(a & b) & !(a & c) & !(b & c)");
+    let f = manager.parse("a & !b & !c | !a & b & !c | !a & !b & c");
+    let simplified = manager.simplify(&f, &care);
+    assert!(simplified == manager.parse("a | b | c"));
+    assert!(simplified.node_count() < f.node_count());
+    assert!(&manager.constrain(&f, &care) & &care == &f & &care);
+}
+
+#[test]
+fn verdict_test() {
+    let manager = Manager::new();
+    let (f, g) = (manager.parse("a -> b"), manager.parse("!b -> !a"));
+    // Equal functions share their node, so nothing is computed
+    let statistics = manager.cache_statistics();
+    assert!(manager.equivalent(&f, &g).holds());
+    assert_eq!(manager.cache_statistics(), statistics);
+    assert!(manager.entails(&manager.parse("a & b"), &f).holds());
+    assert!(manager.is_tautology(&manager.parse("a | !a")).holds());
+    assert!(manager.is_satisfiable(&f).holds());
+    assert_eq!(
+        manager.is_satisfiable(&manager.parse("a & !a")),
+        Verdict::Counterexample(vec![])
+    );
+    // The counterexamples tell the formulas apart
+    let h = manager.parse("b -> a");
+    let under = |verdict, formula: &FormulaRoot<String>| match verdict {
+        Verdict::Counterexample(cube) => manager.restrict(formula, &cube.into_iter().collect()),
+        Verdict::Holds => panic!("The property should fail"),
+    };
+    let (true_formula, false_formula) = (manager.constant(true), manager.constant(false));
+    assert!(under(manager.equivalent(&f, &h), &f) == !under(manager.equivalent(&f, &h), &h));
+    assert!(under(manager.entails(&f, &h), &f) == true_formula);
+    assert!(under(manager.entails(&f, &h), &h) == false_formula);
+    assert!(under(manager.is_tautology(&f), &f) == false_formula);
+}
+
+#[test]
+fn garbage_collection_threshold_test() {
+    let manager = Manager::new();
+    manager.set_garbage_collection_threshold(None);
+    drop(manager.add_formula("a & b | c & d").unwrap());
+    let node_count = manager.node_count();
+    manager.add_formula("e").unwrap();
+    assert!(manager.node_count() > node_count);
+    manager.set_garbage_collection_threshold(Some(0));
+    manager.add_formula("e").unwrap();
+    assert_eq!(manager.node_count(), 1);
+}
+
+#[test]
+fn group_test() {
+    let manager = Manager::new();
+    let f = manager
+        .add_formula("(x1 <-> y1) & (x2 <-> y2) & (x3 <-> y3)")
+        .unwrap();
+    manager.group(["x2", "y2"].map(String::from));
+    manager.group(["x3", "y3"].map(String::from));
+    assert_eq!(
+        manager.variable_order(),
+        ["x1", "y1", "x2", "y2", "x3", "y3"]
+    );
+    let node_count = f.node_count();
+    manager.declare_order(["z", "x4", "y4"].map(String::from));
+    let g = &f & &manager.add_formula("z | (x4 <-> y4)").unwrap();
+    manager.reorder_by_window_permutation(3);
+    manager.reorder();
+    assert_eq!(f.node_count(), node_count);
+    assert!(g == &f & &manager.add_formula("(y4 <-> x4) | z").unwrap());
+    let order = manager.variable_order();
+    for pair in [["x2", "y2"], ["x3", "y3"]] {
+        let level = order.iter().position(|name| name == pair[0]).unwrap();
+        assert_eq!(order[level + 1], pair[1]);
+    }
+}
+
+#[test]
+fn manager_optimal_order_test() {
+    let manager = Manager::new();
+    let f = manager
+        .add_formula("order: a < b < c < d\n (a <-> c) & (b <-> d)")
+        .unwrap();
+    let (order, size) = manager.optimal_order(&f);
+    assert!(size < f.node_count());
+    let order: Vec<&str> = order.iter().map(String::as_str).collect();
+    let g = crate::construct_robdd_with_order("(a <-> c) & (b <-> d)", &order).unwrap();
+    assert_eq!(g.node_count(), size);
+}
+
+#[test]
+fn declare_order_test() {
+    let manager = Manager::new();
+    manager.declare_order(["b", "a"]);
+    let a = manager.var("a");
+    let b = manager.var("b");
+    let a_and_b = &a & &b;
+    let context = manager.0.borrow();
+    let root = a_and_b.node_handler();
+    assert_eq!(root.get_element(&context.diagram), Element::Variable(&0));
+    assert_eq!(context.inverse_table, vec!["b", "a"]);
+}
@@ -0,0 +1,282 @@
+//! Static variable orders computed from the parse tree of a formula, before it is built.
+
+use std::{
+    collections::{BTreeSet, HashMap, HashSet},
+    fmt::Display,
+    hash::Hash,
+    str::FromStr,
+};
+
+use crate::formula_parser::ParserNode;
+
+/// A way to choose the variable order of a formula from its structure.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum OrderingHeuristic {
+    /// Variables in order of their first appearance, which is the default order.
+    Appearance,
+    /// Variables in the order a depth-first traversal reaches them, visiting the deeper
+    /// operand of each operator first.
+    DepthFirst,
+    /// Every operator splits its weight equally among its operands, starting with weight 1
+    /// at the root; variables with a larger total weight come first.
+    WeightedFanIn,
+    /// The FORCE heuristic: the variables of each subformula are pulled towards their center
+    /// of gravity until the total span of the subformulas stops shrinking.
+    Force,
+    /// Tries each of the other heuristics and keeps the order giving the fewest nodes.
+    Best,
+}
+
+const HEURISTICS: [OrderingHeuristic; 4] = [
+    OrderingHeuristic::Appearance,
+    OrderingHeuristic::DepthFirst,
+    OrderingHeuristic::WeightedFanIn,
+    OrderingHeuristic::Force,
+];
+
+/// The outcome of building a formula with an ordering heuristic.
+#[derive(Clone, Debug)]
+pub struct OrderingReport<T> {
+    /// The heuristic the order comes from; never `Best`, which reports the winning one.
+    pub heuristic: OrderingHeuristic,
+    pub order: Vec<T>,
+    /// The number of nodes of the resulting diagram.
+    pub node_count: usize,
+}
+
+impl Display for OrderingHeuristic {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let name = match self {
+            OrderingHeuristic::Appearance => "appearance",
+            OrderingHeuristic::DepthFirst => "depth-first",
+            OrderingHeuristic::WeightedFanIn => "weighted-fan-in",
+            OrderingHeuristic::Force => "force",
+            OrderingHeuristic::Best => "best",
+        };
+        write!(f, "{name}")
+    }
+}
+
+impl FromStr for OrderingHeuristic {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        HEURISTICS
+            .into_iter()
+            .chain([OrderingHeuristic::Best])
+            .find(|heuristic| heuristic.to_string() == s)
+            .ok_or_else(|| format!("Unknown heuristic {s}"))
+    }
+}
+
+impl OrderingHeuristic {
+    // The heuristics to try
+    pub(crate) fn candidates(self) -> Vec<OrderingHeuristic> {
+        match self {
+            OrderingHeuristic::Best => HEURISTICS.to_vec(),
+            heuristic => vec![heuristic],
+        }
+    }
+
+    /// The order of the variables of `formula` given by the heuristic.
+    /// `Best` needs to build the formula, so it falls back to `Appearance` here.
+    pub(crate) fn order<T>(self, formula: &ParserNode<T>) -> Vec<T>
+    where
+        T: Eq + Hash + Clone,
+    {
+        let appearance = depth_first_order(formula, false);
+        match self {
+            OrderingHeuristic::Appearance | OrderingHeuristic::Best => appearance,
+            OrderingHeuristic::DepthFirst => depth_first_order(formula, true),
+            OrderingHeuristic::WeightedFanIn => {
+                let mut weights = HashMap::new();
+                weigh(formula, 1.0, &mut weights);
+                let mut order = appearance;
+                order.sort_by(|a, b| weights[b].total_cmp(&weights[a]));
+                order
+            }
+            OrderingHeuristic::Force => force_order(formula, appearance),
+        }
+    }
+}
+
+// The variables in the order they are reached, each once
+fn depth_first_order<T>(formula: &ParserNode<T>, deeper_first: bool) -> Vec<T>
+where
+    T: Eq + Hash + Clone,
+{
+    fn visit<'a, T>(
+        node: &'a ParserNode<T>,
+        deeper_first: bool,
+        seen: &mut HashSet<&'a T>,
+        order: &mut Vec<T>,
+    ) where
+        T: Eq + Hash + Clone,
+    {
+        match node {
+            ParserNode::Unary(_, operand) | ParserNode::Quantified(_, _, operand) => {
+                visit(operand, deeper_first, seen, order)
+            }
+            ParserNode::Binary(_, (left, right)) => {
+                let (first, second) = match deeper_first && depth(right) > depth(left) {
+                    true => (right, left),
+                    false => (left, right),
+                };
+                visit(first, deeper_first, seen, order);
+                visit(second, deeper_first, seen, order);
+            }
+            ParserNode::Variable(var) => {
+                if seen.insert(var) {
+                    order.push(var.clone());
+                }
+            }
+            ParserNode::Leaf(_) => {}
+        }
+    }
+    let mut order = vec![];
+    visit(formula, deeper_first, &mut HashSet::new(), &mut order);
+    order
+}
+
+fn depth<T>(node: &ParserNode<T>) -> usize {
+    match node {
+        ParserNode::Unary(_, operand) | ParserNode::Quantified(_, _, operand) => 1 + depth(operand),
+        ParserNode::Binary(_, (left, right)) => 1 + usize::max(depth(left), depth(right)),
+        ParserNode::Variable(_) | ParserNode::Leaf(_) => 0,
+    }
+}
+
+fn weigh<'a, T>(node: &'a ParserNode<T>, weight: f64, weights: &mut HashMap<&'a T, f64>)
+where
+    T: Eq + Hash,
+{
+    match node {
+        ParserNode::Unary(_, operand) | ParserNode::Quantified(_, _, operand) => {
+            weigh(operand, weight, weights)
+        }
+        ParserNode::Binary(_, (left, right)) => {
+            weigh(left, weight / 2.0, weights);
+            weigh(right, weight / 2.0, weights);
+        }
+        ParserNode::Variable(var) => *weights.entry(var).or_default() += weight,
+        ParserNode::Leaf(_) => {}
+    }
+}
+
+// Starts from `initial` and moves every variable to the average center of gravity of the
+// subformulas containing it, as long as the total span of the subformulas decreases
+fn force_order<T>(formula: &ParserNode<T>, initial: Vec<T>) -> Vec<T>
+where
+    T: Eq + Hash + Clone,
+{
+    // Variables are numbered by their index in `initial`; a subformula is a hyperedge
+    // connecting its variables
+    let index: HashMap<&T, usize> = initial
+        .iter()
+        .enumerate()
+        .map(|(i, var)| (var, i))
+        .collect();
+    let mut hyperedges = vec![];
+    collect_hyperedges(formula, &index, &mut hyperedges);
+    hyperedges.retain(|edge| edge.len() > 1 && edge.len() < initial.len());
+    hyperedges.sort();
+    hyperedges.dedup();
+
+    // `position[v]` is the place of variable `v` in the order
+    let span = |position: &[usize]| -> usize {
+        hyperedges
+            .iter()
+            .map(|edge| {
+                let positions = edge.iter().map(|v| position[*v]);
+                positions.clone().max().unwrap() - positions.min().unwrap()
+            })
+            .sum()
+    };
+    let mut position: Vec<usize> = (0..initial.len()).collect();
+    let mut best_span = span(&position);
+    for _ in 0..initial.len().max(1) * 2 {
+        let mut force = vec![(0.0, 0); initial.len()];
+        for edge in &hyperedges {
+            let center = edge.iter().map(|v| position[*v] as f64).sum::<f64>() / edge.len() as f64;
+            for v in edge {
+                force[*v].0 += center;
+                force[*v].1 += 1;
+            }
+        }
+        let target = |v: usize| match force[v] {
+            (_, 0) => position[v] as f64,
+            (sum, count) => sum / count as f64,
+        };
+        let mut order: Vec<usize> = (0..initial.len()).collect();
+        order.sort_by(|a, b| {
+            target(*a)
+                .total_cmp(&target(*b))
+                .then(position[*a].cmp(&position[*b]))
+        });
+        let mut new_position = vec![0; initial.len()];
+        for (place, v) in order.into_iter().enumerate() {
+            new_position[v] = place;
+        }
+        let new_span = span(&new_position);
+        if new_span >= best_span {
+            break;
+        }
+        best_span = new_span;
+        position = new_position;
+    }
+
+    let mut order: Vec<(usize, T)> = initial
+        .into_iter()
+        .enumerate()
+        .map(|(v, var)| (position[v], var))
+        .collect();
+    order.sort_by_key(|(place, _)| *place);
+    order.into_iter().map(|(_, var)| var).collect()
+}
+
+// Pushes the variable set of every subformula to `hyperedges` and returns the one of `node`
+fn collect_hyperedges<T>(
+    node: &ParserNode<T>,
+    index: &HashMap<&T, usize>,
+    hyperedges: &mut Vec<BTreeSet<usize>>,
+) -> BTreeSet<usize>
+where
+    T: Eq + Hash,
+{
+    let variables = match node {
+        ParserNode::Unary(_, operand) | ParserNode::Quantified(_, _, operand) => {
+            return collect_hyperedges(operand, index, hyperedges)
+        }
+        ParserNode::Binary(_, (left, right)) => {
+            let mut variables = collect_hyperedges(left, index, hyperedges);
+            variables.extend(collect_hyperedges(right, index, hyperedges));
+            variables
+        }
+        ParserNode::Variable(var) => return BTreeSet::from([index[var]]),
+        ParserNode::Leaf(_) => return BTreeSet::new(),
+    };
+    hyperedges.push(variables.clone());
+    variables
+}
+
+#[test]
+fn ordering_heuristic_test() {
+    let (_, formula) = crate::formula_parser::input_parse("a | (b & (c | d)) & c").unwrap();
+    assert_eq!(
+        OrderingHeuristic::Appearance.order(&formula),
+        vec!["a", "b", "c", "d"]
+    );
+    assert_eq!(
+        OrderingHeuristic::DepthFirst.order(&formula),
+        vec!["c", "d", "b", "a"]
+    );
+    assert_eq!(
+        OrderingHeuristic::WeightedFanIn.order(&formula),
+        vec!["a", "c", "b", "d"]
+    );
+    assert_eq!(
+        "weighted-fan-in".parse(),
+        Ok(OrderingHeuristic::WeightedFanIn)
+    );
+    assert!("random".parse::<OrderingHeuristic>().is_err());
+}